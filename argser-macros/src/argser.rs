@@ -1,41 +1,190 @@
-use proc_macro2::{Ident, TokenStream};
+use proc_macro2::{Ident, Span, TokenStream};
 use quote::{quote, ToTokens};
 use syn::Type;
 
 mod attributes;
 pub use attributes::*;
 
+mod struct_options;
+pub use struct_options::*;
+
 enum DefaultValue {
     None,
     Impl,
     Func(Ident),
+    /// The textual Form of the Default, which is parsed like any other Value
+    Literal(String),
+    Expr(Box<syn::Expr>),
+}
+
+impl DefaultValue {
+    /// Generates the Code that evaluates to the Default-Value, if there is
+    /// one, where `name` is the full Name of the Argument
+    fn value_block(&self, name: &TokenStream) -> Option<TokenStream> {
+        match self {
+            Self::None => None,
+            Self::Impl => Some(quote! { Default::default() }),
+            Self::Func(default_func_name) => Some(quote! { #default_func_name() }),
+            Self::Literal(text) => Some(quote! {
+                match argser::ParseFromArgs::parse(vec![#text.to_owned()]) {
+                    Ok(v) => v,
+                    Err(e) => {
+                        return Err(argser::ParseError::InvalidDefault {
+                            name: #name.to_owned(),
+                            error: Box::new(e),
+                        })
+                    }
+                }
+            }),
+            Self::Expr(expr) => Some(quote! { #expr }),
+        }
+    }
+
+    /// The textual Form of the Default-Value, as it is shown to the User,
+    /// which for Expressions is their Source as printed from the Tokens, so
+    /// its Whitespace may differ from how it was written
+    fn text(&self) -> Option<String> {
+        match self {
+            Self::None | Self::Impl | Self::Func(_) => None,
+            Self::Literal(text) => Some(text.clone()),
+            Self::Expr(expr) => Some(expr.to_token_stream().to_string()),
+        }
+    }
+}
+
+/// The Name of a Field that is not renamed explicitly, so it is renamed by
+/// `argser::RenameRule` while parsing, using either the Naming-Convention of
+/// its Struct or the one inherited from the parent Struct
+struct ConventionName {
+    /// The Prefix of the Struct, which is not renamed
+    prefix: Option<String>,
+    /// The Name of the Field itself
+    field: String,
 }
 
 /// A single Field of a Struct that should be parsed
 struct ParseField {
+    /// The Name of the local Variable that holds the parsed Value
     ident: syn::Ident,
+    /// The Field in the Struct, which is either named or an Index for Tuples
+    member: syn::Member,
     ty: Type,
+    /// The Type of the Values stored in a Map or List, which is the Type
+    /// originally written on the Field
+    inner_ty: Type,
     arg_name: String,
+    /// The Parts of the Name, if the Field is renamed by a Naming-Convention,
+    /// in which case `arg_name` is only the Name without any Naming-Convention
+    convention_name: Option<ConventionName>,
+    /// The Naming-Convention configured on the Struct, which is passed on
+    /// to its SubCategories
+    rename_all: Option<RenameRule>,
+    /// The Character that separates the Segments of the Names, which is
+    /// configured on the Struct
+    separator: char,
     value: FieldValue,
     default_func: DefaultValue,
+    file_indirection: bool,
+    env: Option<String>,
+    secret: bool,
+    lenient: bool,
+    default_on_invalid: bool,
 }
 
 impl ParseField {
-    pub fn parse(field: &syn::Field) -> ParseField {
-        let ident = field.ident.as_ref().unwrap().clone();
-        let mut arg_name = ident.to_string();
+    pub fn parse(
+        index: usize,
+        field: &syn::Field,
+        options: &StructOptions,
+    ) -> syn::Result<ParseField> {
+        let (ident, member, field_name) = match &field.ident {
+            Some(ident) => (
+                ident.clone(),
+                syn::Member::Named(ident.clone()),
+                ident.to_string(),
+            ),
+            None => (
+                quote::format_ident!("field_{}", index),
+                syn::Member::Unnamed(syn::Index::from(index)),
+                index.to_string(),
+            ),
+        };
+        let mut rename = None;
         let mut ty = field.ty.clone();
+        let mut inner_ty = field.ty.clone();
         let mut value = FieldValue::Primitive;
         let mut default_func = DefaultValue::None;
+        let mut file_indirection = false;
+        let mut env = None;
+        let mut secret = false;
+        let mut lenient = false;
+        let mut lenient_span = Span::call_site();
+        let mut default_on_invalid = false;
+
+        // The first Attribute that determined the Kind of the Field or its
+        // Default, which are used to report contradicting Attributes
+        let mut kind_attr: Option<&'static str> = None;
+        let mut default_attr: Option<&'static str> = None;
+        // The Attributes that only apply to Primitive Fields
+        let mut primitive_attrs: Vec<(Span, &'static str)> = Vec::new();
+
+        let attributes = parse_attributes(&field.attrs)?;
+        for SpannedAttribute { span, attr } in attributes {
+            let attr_name = attr.name();
+            match &attr {
+                FieldAttribute::SubCategory
+                | FieldAttribute::Skip
+                | FieldAttribute::Compute { .. }
+                | FieldAttribute::Flatten
+                | FieldAttribute::Map(_)
+                | FieldAttribute::List(_) => {
+                    if let Some(previous) = kind_attr {
+                        return Err(syn::Error::new(
+                            span,
+                            format!("`{}` can not be combined with `{}`", attr_name, previous),
+                        ));
+                    }
+                    kind_attr = Some(attr_name);
+                }
+                FieldAttribute::DefaultFunc { .. }
+                | FieldAttribute::Default_
+                | FieldAttribute::DefaultLiteral { .. }
+                | FieldAttribute::DefaultExpr { .. } => {
+                    if let Some(previous) = default_attr {
+                        return Err(syn::Error::new(
+                            span,
+                            format!("The Default-Value was already set using `{}`", previous),
+                        ));
+                    }
+                    default_attr = Some(attr_name);
+                    primitive_attrs.push((span, attr_name));
+                }
+                FieldAttribute::FileIndirection
+                | FieldAttribute::Env { .. }
+                | FieldAttribute::Secret
+                | FieldAttribute::DefaultOnInvalid => {
+                    primitive_attrs.push((span, attr_name));
+                }
+                FieldAttribute::Rename { .. } | FieldAttribute::Lenient => {}
+            };
 
-        let attributes = parse_attributes(&field.attrs);
-        for attr in attributes {
             match attr {
                 FieldAttribute::SubCategory => {
                     value = FieldValue::SubCategory;
                 }
+                FieldAttribute::Skip => {
+                    value = FieldValue::Skip;
+                }
+                FieldAttribute::Compute { expr } => {
+                    value = FieldValue::Computed(Box::new(expr));
+                }
+                FieldAttribute::Flatten => {
+                    value = FieldValue::Flatten {
+                        prefix: options.prefix.clone(),
+                    };
+                }
                 FieldAttribute::Rename { name } => {
-                    arg_name = name;
+                    rename = Some((span, name));
                 }
                 FieldAttribute::DefaultFunc { func } => {
                     default_func = DefaultValue::Func(func);
@@ -43,267 +192,945 @@ impl ParseField {
                 FieldAttribute::Default_ => {
                     default_func = DefaultValue::Impl;
                 }
-                FieldAttribute::Map { sub } => {
-                    let new_type: syn::Type =
-                        syn::parse2(quote! { std::collections::HashMap<String, #ty> }).unwrap();
-
-                    let sub_value = match sub {
-                        Some(s) => match s.to_string().as_ref() {
-                            "subcategory" => FieldValue::SubCategory,
-                            _ => FieldValue::Primitive,
-                        },
-                        _ => FieldValue::Primitive,
-                    };
-                    value = FieldValue::Map(Box::new(sub_value), ty.clone());
-
-                    ty = new_type;
+                FieldAttribute::DefaultLiteral { value } => {
+                    default_func = DefaultValue::Literal(value);
+                }
+                FieldAttribute::DefaultExpr { expr } => {
+                    default_func = DefaultValue::Expr(Box::new(expr));
+                }
+                FieldAttribute::FileIndirection => {
+                    file_indirection = true;
+                }
+                FieldAttribute::Env { name } => {
+                    env = Some(name);
+                }
+                FieldAttribute::Secret => {
+                    secret = true;
+                }
+                FieldAttribute::Lenient => {
+                    lenient = true;
+                    lenient_span = span;
+                }
+                FieldAttribute::DefaultOnInvalid => {
+                    default_on_invalid = true;
+                }
+                attr @ FieldAttribute::Map(_) | attr @ FieldAttribute::List(_)
+                    if options.explicit_types =>
+                {
+                    let (n_value, n_inner_ty) = FieldValue::explicit_container(attr, &field.ty)?;
+                    value = n_value;
+                    inner_ty = n_inner_ty;
+                }
+                attr @ FieldAttribute::Map(_) | attr @ FieldAttribute::List(_) => {
+                    let (n_value, n_ty) = FieldValue::container(attr, &field.ty);
+                    value = n_value;
+                    ty = n_ty;
                 }
             };
         }
 
-        ParseField {
+        if let Some(kind) = kind_attr {
+            // Skipped Fields can still use a Default to be initialized with
+            let allowed: &[&str] = if kind == "skip" {
+                &["default", "default_func", "default_expr"]
+            } else {
+                &[]
+            };
+            if let Some((span, name)) = primitive_attrs
+                .iter()
+                .find(|(_, name)| !allowed.contains(name))
+            {
+                return Err(syn::Error::new(
+                    *span,
+                    format!("`{}` can not be combined with `{}`", name, kind),
+                ));
+            }
+            if lenient && !matches!(kind, "map" | "list") {
+                return Err(syn::Error::new(
+                    lenient_span,
+                    format!("`lenient` can not be combined with `{}`", kind),
+                ));
+            }
+        }
+        if default_on_invalid && default_attr.is_none() {
+            let span = primitive_attrs
+                .iter()
+                .find(|(_, name)| *name == "default_on_invalid")
+                .map(|(span, _)| *span)
+                .unwrap_or_else(Span::call_site);
+            return Err(syn::Error::new(
+                span,
+                "`default_on_invalid` requires a Default-Value",
+            ));
+        }
+        let rename = match rename {
+            Some((span, _)) if kind_attr == Some("flatten") => {
+                return Err(syn::Error::new(
+                    span,
+                    "`rename` can not be combined with `flatten`",
+                ));
+            }
+            Some((_, name)) => Some(name),
+            None => None,
+        };
+        let convention_name = match &value {
+            _ if rename.is_some() || field.ident.is_none() => None,
+            FieldValue::Primitive
+            | FieldValue::SubCategory
+            | FieldValue::Map { .. }
+            | FieldValue::List { .. } => Some(ConventionName {
+                prefix: options.prefix.clone(),
+                field: field_name.clone(),
+            }),
+            FieldValue::Skip | FieldValue::Computed(_) | FieldValue::Flatten { .. } => None,
+        };
+        let arg_name = options.arg_name(&field_name, rename);
+
+        Ok(ParseField {
             ident,
+            member,
             ty,
+            inner_ty,
             arg_name,
+            convention_name,
+            rename_all: options.rename_all,
+            separator: options.separator(),
             value,
             default_func,
+            file_indirection,
+            env,
+            secret,
+            lenient,
+            default_on_invalid,
+        })
+    }
+
+    /// The Segments of the given Name, as a Slice that can be passed to
+    /// [`Value::pointer_path`](argser::Value::pointer_path)
+    fn path(&self, name: &str) -> TokenStream {
+        let segments = split_name(name, self.separator);
+        quote! { &[#(#segments),*] }
+    }
+
+    /// The local Variable that holds the Name of the Field renamed by its
+    /// Naming-Convention, if there is one
+    fn renamed_ident(&self) -> Ident {
+        quote::format_ident!("argser_renamed_{}", self.ident)
+    }
+
+    /// Declares the local Variable for the Name of the Field renamed by the
+    /// Naming-Convention of its Struct or the inherited one in `rule`, if the
+    /// Field is renamed by a Naming-Convention at all
+    fn renamed_decl(&self) -> TokenStream {
+        let convention = match &self.convention_name {
+            Some(c) => c,
+            None => return quote! {},
+        };
+        let renamed = self.renamed_ident();
+        let field = &convention.field;
+
+        match self.rename_all {
+            Some(rule) => quote! {
+                let #renamed: Option<String> = Some(#rule.apply(#field));
+            },
+            None => quote! {
+                let #renamed: Option<String> = rule.map(|rule| rule.apply(#field));
+            },
+        }
+    }
+
+    /// The full Name of the Argument as an Expression of Type `&str`, which
+    /// uses the Variable declared by [`ParseField::renamed_decl`] if the Field
+    /// is renamed by a Naming-Convention
+    fn name(&self) -> TokenStream {
+        let arg_name = &self.arg_name;
+        let prefix = match &self.convention_name {
+            Some(convention) => &convention.prefix,
+            None => return quote! { #arg_name },
+        };
+        let renamed = self.renamed_ident();
+
+        match prefix {
+            Some(prefix) => {
+                let separator = self.separator;
+                quote! {
+                    (&*match &#renamed {
+                        Some(renamed) => std::borrow::Cow::Owned(format!("{}{}{}", #prefix, #separator, renamed)),
+                        None => std::borrow::Cow::Borrowed(#arg_name),
+                    })
+                }
+            }
+            None => quote! {
+                (match &#renamed {
+                    Some(renamed) => renamed.as_str(),
+                    None => #arg_name,
+                })
+            },
+        }
+    }
+
+    /// The Segments of the full Name of the Argument, like
+    /// [`ParseField::path`], which depend on the Naming-Convention like
+    /// [`ParseField::name`]
+    fn name_path(&self) -> TokenStream {
+        let prefix = match &self.convention_name {
+            Some(convention) => &convention.prefix,
+            None => return self.path(&self.arg_name),
+        };
+        let renamed = self.renamed_ident();
+        let separator = self.separator;
+        let segments = split_name(&self.arg_name, separator);
+        let prefix = prefix
+            .as_deref()
+            .map(|p| split_name(p, separator))
+            .unwrap_or_default();
+
+        quote! {
+            &*match &#renamed {
+                Some(renamed) => std::borrow::Cow::Owned(
+                    [#(#prefix),*]
+                        .iter()
+                        .copied()
+                        .chain(renamed.split(#separator))
+                        .collect::<Vec<&str>>(),
+                ),
+                None => std::borrow::Cow::Borrowed(&[#(#segments),*][..]),
+            }
+        }
+    }
+
+    /// The Naming-Convention inherited by the SubCategories of the Field, as
+    /// an Expression of Type `Option<argser::RenameRule>`
+    fn nested_rule(&self) -> TokenStream {
+        match self.rename_all {
+            Some(rule) => quote! { Some(#rule) },
+            None => quote! { rule },
         }
     }
 
     pub fn parse_block(&self) -> TokenStream {
-        let mut name = self.arg_name.clone();
+        let separator = self.separator;
+        let name = self.name();
         match &self.value {
             FieldValue::Primitive => {
-                let parse_fn = match &self.default_func {
-                    DefaultValue::None => quote! { argser::ParseFromArgs::parse(value)? },
-                    DefaultValue::Impl => {
-                        quote! { argser::ParseFromArgs::parse_with_default(value) }
+                let on_missing = self.default_func.value_block(&name).unwrap_or_else(|| {
+                    quote! {
+                        return Err(argser::ParseError::MissingParam {
+                            name: #name.to_owned(),
+                        })
                     }
-                    DefaultValue::Func(default_func_name) => {
-                        quote! { argser::ParseFromArgs::parse_with_default_fn(value, #default_func_name) }
+                });
+                // `default_on_invalid` is only accepted together with a Default
+                let on_invalid = if self.default_on_invalid {
+                    quote! { Err(_) => #on_missing }
+                } else {
+                    quote! {
+                        Err(e) => {
+                            return Err(argser::ParseError::InvalidArgument {
+                                name: #name.to_owned(),
+                                error: Box::new(e),
+                            })
+                        }
+                    }
+                };
+
+                let load_value = if self.file_indirection {
+                    quote! {
+                        {
+                            let mut format = ctx.key_format().unwrap_or_default();
+                            format.set_separator(#separator);
+                            argser::load_file_indirection_value(node, #name, &format)?
+                        }
                     }
+                } else {
+                    let path = self.name_path();
+                    quote! { node.lookup_path(#path, &ctx.key_format().unwrap_or_default()) }
                 };
+                let load_value = match &self.env {
+                    Some(env) => quote! {
+                        {
+                            let mut value = #load_value;
+                            if value.is_empty() {
+                                if let Some(v) = ctx.env_var(#env) {
+                                    value = argser::Value::String(v);
+                                }
+                            }
+                            value
+                        }
+                    },
+                    None => load_value,
+                };
+
+                if self.lenient {
+                    quote! {
+                        {
+                            let value = #load_value;
+                            let missing = value.is_empty();
 
+                            let mut skipped = Vec::new();
+                            let result = match argser::ParseFromArgs::parse_value_lenient(value, &mut skipped) {
+                                Ok(v) => v,
+                                Err(_) if missing => #on_missing,
+                                #on_invalid,
+                            };
+                            for error in skipped {
+                                ctx.warn(#name, error);
+                            }
+
+                            result
+                        }
+                    }
+                } else {
+                    quote! {
+                        {
+                            let value = #load_value;
+                            let missing = value.is_empty();
+
+                            match argser::ParseFromArgs::parse_value(value) {
+                                Ok(v) => v,
+                                Err(_) if missing => #on_missing,
+                                #on_invalid,
+                            }
+                        }
+                    }
+                }
+            }
+            FieldValue::Skip => self
+                .default_func
+                .value_block(&name)
+                .unwrap_or_else(|| quote! { Default::default() }),
+            FieldValue::Computed(expr) => quote! { #expr },
+            FieldValue::Flatten { prefix: None } => {
+                let from_value = nested_from_value(self.rename_all, quote! { node });
+                quote! { #from_value? }
+            }
+            FieldValue::SubCategory | FieldValue::Flatten { .. } => {
+                let (name, path) = match &self.value {
+                    FieldValue::Flatten {
+                        prefix: Some(prefix),
+                    } => (quote! { #prefix }, self.path(prefix)),
+                    _ => (name, self.name_path()),
+                };
+                let from_value = nested_from_value(self.rename_all, quote! { sub_category });
                 quote! {
                     {
-                        let value = args.get(#name).map(|v| v.clone()).unwrap_or_else(|| Vec::new());
+                        let empty = argser::Value::table();
+                        let sub_category = node
+                            .pointer_path(#path, &ctx.key_format().unwrap_or_default())
+                            .unwrap_or(&empty);
 
-                        #parse_fn
+                        #from_value
+                            .map_err(|e| e.with_prefix_separated(#name, #separator))?
                     }
                 }
             }
-            FieldValue::SubCategory => {
-                name.push('.');
+            FieldValue::Map { .. } | FieldValue::List { .. } => {
+                let container_block =
+                    self.value
+                        .container_block(self.lenient, separator, self.rename_all);
+                let path = self.name_path();
+
                 quote! {
                     {
-                        let mut sub_category = std::collections::HashMap::<String, Vec<String>>::new();
-                        for (key, value) in args.iter() {
-                            let n_key = match key.strip_prefix(#name) {
-                                Some(k) => k,
-                                None => continue,
-                            };
-                            sub_category.insert(n_key.to_owned(), value.to_owned());
-                        }
+                        let empty = argser::Value::table();
+                        let node = node
+                            .pointer_path(#path, &ctx.key_format().unwrap_or_default())
+                            .unwrap_or(&empty);
 
-                        argser::FromArgs::parse(sub_category)?
+                        let prefix = #name.to_owned();
+                        #container_block
                     }
                 }
             }
-            FieldValue::Map(sub, _) => {
-                name.push('.');
+        }
+    }
+}
 
-                let ty = &self.ty;
+enum FieldValue {
+    Primitive,
+    SubCategory,
+    /// The Field is not parsed at all and only initialized with its Default
+    Skip,
+    /// The Field is derived from the other, already parsed, Fields using the
+    /// given Expression
+    Computed(Box<syn::Expr>),
+    /// A SubCategory whose Arguments are merged into the Namespace of the
+    /// parent, which only contains the Prefix of the parent Struct if any
+    Flatten {
+        prefix: Option<String>,
+    },
+    Map {
+        value: Box<FieldValue>,
+        /// The complete Type of the Map
+        ty: Box<Type>,
+    },
+    List {
+        value: Box<FieldValue>,
+    },
+}
 
-                match sub.as_ref() {
-                    FieldValue::Primitive => {
-                        quote! {
-                            {
-                                let mut sub_category = std::collections::HashMap::<String, Vec<String>>::new();
-                                for (key, value) in args.iter() {
-                                    let n_key = match key.strip_prefix(#name) {
-                                        Some(k) => k,
-                                        None => continue,
-                                    };
+impl FieldValue {
+    /// The Value that is stored in the innermost Map or List, or the Value
+    /// itself for anything else
+    fn leaf(&self) -> &FieldValue {
+        match self {
+            FieldValue::Map { value, .. } | FieldValue::List { value } => value.leaf(),
+            other => other,
+        }
+    }
 
-                                    sub_category.insert(n_key.to_owned(), value.to_owned());
-                                }
+    /// Creates the FieldValue for the given Map- or List-Attribute, which
+    /// stores Values of the given Type, together with the resulting Type of
+    /// the Field
+    fn container(attr: FieldAttribute, inner_ty: &Type) -> (FieldValue, Type) {
+        match attr {
+            FieldAttribute::SubCategory => (FieldValue::SubCategory, inner_ty.clone()),
+            FieldAttribute::Map(container) => {
+                let (value, value_ty) = match container.inner {
+                    Some(inner) => Self::container(*inner, inner_ty),
+                    None => (FieldValue::Primitive, inner_ty.clone()),
+                };
+                let key_ty = container
+                    .key
+                    .unwrap_or_else(|| syn::parse_quote! { String });
 
-                                let mut result = <#ty>::new();
+                let ty: Type = if container.btree {
+                    syn::parse_quote! { std::collections::BTreeMap<#key_ty, #value_ty> }
+                } else {
+                    syn::parse_quote! { std::collections::HashMap<#key_ty, #value_ty> }
+                };
 
-                                for (sub_name, sub_value) in sub_category {
-                                    match argser::ParseFromArgs::parse(sub_value) {
-                                        Ok(res) => {
-                                            result.insert(sub_name, res);
-                                        }
-                                        _ => continue,
-                                    };
-                                }
+                let value = FieldValue::Map {
+                    value: Box::new(value),
+                    ty: Box::new(ty.clone()),
+                };
+                (value, ty)
+            }
+            FieldAttribute::List(container) => {
+                let (value, value_ty) = match container.inner {
+                    Some(inner) => Self::container(*inner, inner_ty),
+                    None => (FieldValue::Primitive, inner_ty.clone()),
+                };
 
-                                result
+                let ty: Type = syn::parse_quote! { Vec<#value_ty> };
+
+                let value = FieldValue::List {
+                    value: Box::new(value),
+                };
+                (value, ty)
+            }
+            _ => (FieldValue::Primitive, inner_ty.clone()),
+        }
+    }
+
+    /// Creates the FieldValue for the given Map- or List-Attribute on a Field
+    /// with the given, fully written out, Type together with the Type of the
+    /// Values stored in the innermost Map or List
+    fn explicit_container(attr: FieldAttribute, ty: &Type) -> syn::Result<(FieldValue, Type)> {
+        let (container, kind) = match attr {
+            FieldAttribute::Map(container) => (container, "Map"),
+            FieldAttribute::List(container) => (container, "List"),
+            FieldAttribute::SubCategory => return Ok((FieldValue::SubCategory, ty.clone())),
+            _ => return Ok((FieldValue::Primitive, ty.clone())),
+        };
+        if container.key.is_some() || container.btree {
+            return Err(syn::Error::new_spanned(
+                ty,
+                "`key` and `btree` are not needed, as the Type of the Map is written out",
+            ));
+        }
+
+        let value_ty = match generic_args(ty).last() {
+            Some(value_ty) => (*value_ty).clone(),
+            None => {
+                return Err(syn::Error::new_spanned(
+                    ty,
+                    format!(
+                        "Expected the Type of a {} with the Type of its Values",
+                        kind
+                    ),
+                ))
+            }
+        };
+        let (value, inner_ty) = match container.inner {
+            Some(inner) => Self::explicit_container(*inner, &value_ty)?,
+            None => (FieldValue::Primitive, value_ty),
+        };
+
+        let value = Box::new(value);
+        let value = match kind {
+            "Map" => FieldValue::Map {
+                value,
+                ty: Box::new(ty.clone()),
+            },
+            _ => FieldValue::List { value },
+        };
+        Ok((value, inner_ty))
+    }
+
+    /// Generates the Code to parse a single non-Primitive Entry of a Map or
+    /// List from the Tree of Arguments in `node`, whose full Name is stored
+    /// in `prefix`
+    fn entry_block(
+        &self,
+        lenient: bool,
+        separator: char,
+        rename_all: Option<RenameRule>,
+    ) -> TokenStream {
+        match self {
+            FieldValue::SubCategory => {
+                let on_error = on_error(
+                    lenient,
+                    quote! { prefix },
+                    quote! { e.with_prefix_separated(&prefix, #separator) },
+                );
+                let from_value = nested_from_value(rename_all, quote! { node });
+
+                quote! {
+                    match #from_value {
+                        Ok(v) => v,
+                        Err(e) => #on_error,
+                    }
+                }
+            }
+            _ => self.container_block(lenient, separator, rename_all),
+        }
+    }
+
+    /// Generates the Code to parse a Map or List from the Tree of Arguments in
+    /// `node`, which belongs to the Map or List whose full Name is stored in
+    /// `prefix`, using the given Separator for the Names of the Entries
+    fn container_block(
+        &self,
+        lenient: bool,
+        separator: char,
+        rename_all: Option<RenameRule>,
+    ) -> TokenStream {
+        let invalid_argument = quote! {
+            argser::ParseError::InvalidArgument {
+                name: entry_arg,
+                error: Box::new(e),
+            }
+        };
+
+        match self {
+            FieldValue::Map { value, ty } => match value.as_ref() {
+                FieldValue::Primitive => {
+                    let on_error = on_error(lenient, quote! { entry_arg }, invalid_argument);
+
+                    quote! {
+                        {
+                            let mut result = <#ty>::new();
+
+                            let format = argser::KeyFormat::new(#separator);
+                            for (entry_name, entry_value) in node.flat_entries(&format) {
+                                let entry_arg = format.join(&prefix, &entry_name);
+                                let entry_key = match argser::ParseFromArgs::parse(vec![entry_name]) {
+                                    Ok(k) => k,
+                                    Err(e) => #on_error,
+                                };
+                                let entry_value = match argser::ParseFromArgs::parse_value(entry_value.clone()) {
+                                    Ok(v) => v,
+                                    Err(e) => #on_error,
+                                };
+
+                                result.insert(entry_key, entry_value);
                             }
+
+                            result
                         }
                     }
-                    FieldValue::SubCategory => {
+                }
+                inner => {
+                    let on_key_error = on_error(
+                        lenient,
+                        quote! { prefix },
                         quote! {
-                            {
-                                let mut sub_category = std::collections::HashMap::<String, std::collections::HashMap<String, Vec<String>>>::new();
-                                for (key, value) in args.iter() {
-                                    let n_key = match key.strip_prefix(#name) {
-                                        Some(k) => k,
-                                        None => continue,
-                                    };
+                            argser::ParseError::InvalidArgument {
+                                name: prefix,
+                                error: Box::new(e),
+                            }
+                        },
+                    );
+                    let parse_entry = inner.entry_block(lenient, separator, rename_all);
 
-                                    let (name, sub_key) = match n_key.find('.') {
-                                        Some(i) => (&n_key[..i], &n_key[(i+1)..]),
-                                        None => continue,
-                                    };
+                    quote! {
+                        {
+                            let mut result = <#ty>::new();
 
+                            for (entry_name, node) in node.as_table().into_iter().flatten() {
+                                if entry_name.is_empty() {
+                                    continue;
+                                }
 
-                                    match sub_category.get_mut(name) {
-                                        Some(sub_value) => {
-                                            sub_value.insert(sub_key.to_owned(), value.to_owned());
-                                        }
-                                        None => {
-                                            let mut tmp = std::collections::HashMap::new();
-                                            tmp.insert(sub_key.to_owned(), value.to_owned());
+                                let prefix = argser::KeyFormat::new(#separator).join(&prefix, entry_name);
+                                let entry_key = match argser::ParseFromArgs::parse(vec![entry_name.clone()]) {
+                                    Ok(k) => k,
+                                    Err(e) => #on_key_error,
+                                };
+                                let entry_value = #parse_entry;
 
-                                            sub_category.insert(name.to_owned(), tmp);
-                                        }
-                                    };
-                                }
+                                result.insert(entry_key, entry_value);
+                            }
 
-                                let mut result = <#ty>::new();
+                            result
+                        }
+                    }
+                }
+            },
+            FieldValue::List { value } => {
+                let on_index_error = on_error(lenient, quote! { entry_arg.clone() }, quote! { e });
+
+                let parse_entry = match value.as_ref() {
+                    FieldValue::Primitive => {
+                        let on_error = on_error(lenient, quote! { entry_arg }, invalid_argument);
+
+                        quote! {
+                            let entry_arg = format!("{}{}{}", prefix, #separator, index);
+                            match argser::ParseFromArgs::parse_value(node.clone()) {
+                                Ok(v) => result.push(v),
+                                Err(e) => #on_error,
+                            };
+                        }
+                    }
+                    inner => {
+                        let parse_entry = inner.entry_block(lenient, separator, rename_all);
 
-                                for (sub_name, sub_value) in sub_category {
-                                    match argser::FromArgs::parse(sub_value) {
-                                        Ok(res) => {
-                                            result.insert(sub_name, res);
+                        quote! {
+                            let prefix = format!("{}{}{}", prefix, #separator, index);
+                            result.push(#parse_entry);
+                        }
+                    }
+                };
+
+                quote! {
+                    {
+                        let mut entries = std::collections::BTreeMap::<usize, &argser::Value>::new();
+                        match node {
+                            argser::Value::Array(values) => entries.extend(values.iter().enumerate()),
+                            other => {
+                                for (key, value) in other.as_table().into_iter().flatten() {
+                                    if key.is_empty() {
+                                        continue;
+                                    }
+
+                                    let entry_arg = argser::KeyFormat::new(#separator).join(&prefix, key);
+                                    let index: usize = match key.parse() {
+                                        Ok(i) => i,
+                                        Err(_) => {
+                                            let e = argser::ParseError::InvalidIndex { name: entry_arg.clone() };
+                                            #on_index_error
                                         }
-                                        _ => continue,
                                     };
+                                    entries.insert(index, value);
                                 }
+                            }
+                        };
 
-                                result
+                        let mut result = Vec::with_capacity(entries.len());
+                        for (expected, (index, node)) in entries.into_iter().enumerate() {
+                            if index != expected {
+                                return Err(argser::ParseError::MissingIndex {
+                                    name: format!("{}{}{}", prefix, #separator, expected),
+                                });
                             }
+
+                            #parse_entry
                         }
+
+                        result
                     }
-                    _ => panic!(""),
                 }
             }
+            _ => quote! {},
+        }
+    }
+
+    /// The Pattern for the Names of the Values stored in the innermost Map or
+    /// List, like it is used for their Arguments, or the given Name itself
+    /// for anything else, as an Expression of Type `&str`
+    fn pattern(&self, name: TokenStream, separator: char) -> TokenStream {
+        match self {
+            FieldValue::Map { value, .. } => value.pattern(
+                quote! { &*format!("{}{}{{name}}", #name, #separator) },
+                separator,
+            ),
+            FieldValue::List { value } => value.pattern(
+                quote! { &*format!("{}{}{{index}}", #name, #separator) },
+                separator,
+            ),
+            _ => name,
+        }
+    }
+
+    /// Generates the Code to add the Details for all the Arguments of a Map
+    /// or List, whose Names start with the given Pattern and are separated by
+    /// the given Separator
+    fn arguments_block(
+        &self,
+        pattern: TokenStream,
+        separator: char,
+        inner_ty: &Type,
+        nested_rule: &TokenStream,
+    ) -> TokenStream {
+        let (value, pattern) = match self {
+            FieldValue::Map { value, .. } => (
+                value,
+                quote! { format!("{}{}{{name}}", #pattern, #separator) },
+            ),
+            FieldValue::List { value } => (
+                value,
+                quote! { format!("{}{}{{index}}", #pattern, #separator) },
+            ),
+            _ => return quote! {},
+        };
+
+        match value.as_ref() {
+            FieldValue::Primitive => quote! {
+                args.push(argser::ArgumentDetail::new(#pattern));
+            },
+            FieldValue::SubCategory => quote! {
+                {
+                    let pattern = #pattern;
+                    let raw = <#inner_ty as argser::FromArgs>::arguments_with_rule(#nested_rule);
+                    let extend_iter = raw
+                        .into_iter()
+                        .map(|mut raw| {
+                            raw.name = format!("{}{}{}", pattern, #separator, raw.name);
+                            raw.required = false;
+                            raw
+                        });
+                    args.extend(extend_iter);
+                }
+            },
+            inner => inner.arguments_block(pattern, separator, inner_ty, nested_rule),
         }
     }
 }
 
-enum FieldValue {
-    Primitive,
-    SubCategory,
-    Map(Box<FieldValue>, syn::Type),
+/// Generates the Call to parse a SubCategory from the given Tree of
+/// Arguments, which passes on the Naming-Convention of the Struct if it
+/// configures one, instead of the one it inherited itself
+fn nested_from_value(rename_all: Option<RenameRule>, node: TokenStream) -> TokenStream {
+    match rename_all {
+        Some(rule) => quote! {
+            {
+                let inherited = ctx.rename_rule();
+                ctx.set_rename_rule(Some(#rule));
+                let result = argser::FromArgs::from_value(#node, ctx);
+                ctx.set_rename_rule(inherited);
+                result
+            }
+        },
+        None => quote! { argser::FromArgs::from_value(#node, ctx) },
+    }
+}
+
+/// Generates the Code to handle the Error `e` for a single Entry of a Map or
+/// List, which either records a Warning and skips the Entry, if the Field is
+/// lenient, or returns the given Error
+fn on_error(lenient: bool, name: TokenStream, error: TokenStream) -> TokenStream {
+    if lenient {
+        quote! {
+            {
+                ctx.warn(#name, e);
+                continue;
+            }
+        }
+    } else {
+        quote! {
+            return Err(#error)
+        }
+    }
 }
 
 fn generate_parse_block(fields: &[ParseField]) -> TokenStream {
+    // Computed Fields are generated last, so they can use all the other Fields
+    let (computed, parsed): (Vec<_>, Vec<_>) = fields
+        .iter()
+        .partition(|f| matches!(f.value, FieldValue::Computed(_)));
+
     let mut result = quote! {};
-    for field in fields.iter() {
+    for field in parsed.into_iter().chain(computed) {
         let field_name = &field.ident;
+        let field_ty = &field.ty;
         let parse_block = field.parse_block();
 
         result.extend(quote! {
-            let #field_name = #parse_block;
+            let #field_name: #field_ty = #parse_block;
         });
     }
     result
 }
 
-fn impl_parse(input: &syn::ItemStruct, fields: &[ParseField]) -> TokenStream {
+/// Whether any of the Fields are flattened into the Struct, in which case
+/// the Names of the Arguments could collide
+fn has_flatten(fields: &[ParseField]) -> bool {
+    fields
+        .iter()
+        .any(|f| matches!(f.value, FieldValue::Flatten { .. }))
+}
+
+fn impl_parse(fields: &[ParseField], options: &StructOptions) -> TokenStream {
     let parse_block = generate_parse_block(fields);
+    let renamed_block = if fields.iter().any(|f| f.convention_name.is_some()) {
+        let decls = renamed_decls(fields.iter());
+        quote! {
+            let rule = ctx.rename_rule();
+            let _ = rule;
+            #decls
+        }
+    } else {
+        quote! {}
+    };
 
-    let mut fields = quote! {};
-    for field in input.fields.iter() {
-        let name = field.ident.as_ref().unwrap();
-        fields.extend(quote! {
-            #name,
+    let key_format_block = match options.separator {
+        Some(separator) => quote! {
+            fn key_format() -> argser::KeyFormat {
+                argser::KeyFormat::new(#separator)
+            }
+        },
+        None => quote! {},
+    };
+
+    let mut fields_block = quote! {};
+    for field in fields.iter() {
+        let member = &field.member;
+        let ident = &field.ident;
+        fields_block.extend(quote! {
+            #member: #ident,
         });
     }
 
     quote! {
         fn parse(args: std::collections::HashMap<String, Vec<String>>) -> Result<Self, argser::ParseError> {
+            Self::parse_with_context(args, &mut argser::ParseContext::new())
+        }
+
+        fn parse_with_context(
+            args: std::collections::HashMap<String, Vec<String>>,
+            ctx: &mut argser::ParseContext,
+        ) -> Result<Self, argser::ParseError> {
+            let format = ctx
+                .key_format()
+                .unwrap_or_else(<Self as argser::FromArgs>::key_format);
+            <Self as argser::FromArgs>::check_arguments()?;
+            let value = argser::normalize_keys(
+                argser::Value::from_args_with(args, &format),
+                &format,
+                <Self as argser::FromArgs>::arguments,
+            )?;
+            Self::from_value(&value, ctx)
+        }
+
+        #key_format_block
+
+        fn from_value(
+            node: &argser::Value,
+            ctx: &mut argser::ParseContext,
+        ) -> Result<Self, argser::ParseError> {
+            #renamed_block
+
             #parse_block
 
             Ok(Self {
-                #fields
+                #fields_block
             })
         }
     }
 }
 
-fn impl_arguments(_input: &syn::ItemStruct, fields: &[ParseField]) -> TokenStream {
+/// Generates the Code to push the Details for all the Arguments of the given
+/// Fields into `args`
+fn populate_arguments(fields: &[ParseField]) -> TokenStream {
     let mut populate_block = quote! {};
     for field in fields {
-        let name = &field.arg_name;
+        let name = field.name();
+        let separator = field.separator;
+        let nested_rule = field.nested_rule();
 
         match &field.value {
             FieldValue::Primitive => {
-                let required = match &field.default_func {
-                    DefaultValue::None => true,
-                    DefaultValue::Impl | DefaultValue::Func(_) => false,
+                let required = matches!(field.default_func, DefaultValue::None);
+                let default = match field.default_func.text() {
+                    Some(text) => quote! { .default(#text) },
+                    None => quote! {},
+                };
+                let env = match &field.env {
+                    Some(env) => quote! { .env(#env) },
+                    None => quote! {},
                 };
+                let secret = field.secret;
+                let file_indirection = field.file_indirection;
 
                 populate_block.extend(quote! {
-                    args.push(argser::ArgumentDetail {
-                        name: #name.to_owned(),
-                        required: #required,
-                        description: "".to_owned(),
-                    });
+                    args.push(
+                        argser::ArgumentDetail::new(#name)
+                            .required(#required)
+                            .secret(#secret)
+                            .file_indirection(#file_indirection)
+                            #default
+                            #env
+                    );
                 });
             }
-            FieldValue::SubCategory => {
+            FieldValue::Flatten { prefix: None } => {
+                let ty = &field.ty;
+
+                populate_block.extend(quote! {
+                    args.extend(<#ty as argser::FromArgs>::arguments_with_rule(#nested_rule));
+                });
+            }
+            FieldValue::SubCategory | FieldValue::Flatten { .. } => {
                 let ty = &field.ty;
+                let name = match &field.value {
+                    FieldValue::Flatten {
+                        prefix: Some(prefix),
+                    } => quote! { #prefix },
+                    _ => name,
+                };
 
                 populate_block.extend(quote! {
                     {
-                        let raw = <#ty as argser::FromArgs>::arguments();
+                        let raw = <#ty as argser::FromArgs>::arguments_with_rule(#nested_rule);
                         let extend_iter = raw
                             .into_iter()
                             .map(|mut raw| {
-                                raw.name = format!("{}.{}", #name, raw.name);
+                                raw.name = format!("{}{}{}", #name, #separator, raw.name);
                                 raw
                             });
                         args.extend(extend_iter);
                     }
                 });
             }
-            FieldValue::Map(sub, prev_type) => {
-                let block = match sub.as_ref() {
-                    FieldValue::Primitive => quote! {
-                        args.push(argser::ArgumentDetail {
-                            name: format!("{}.{}", #name, "{name}"),
-                            required: false,
-                            description: "".to_owned(),
-                        });
-                    },
-                    FieldValue::SubCategory => {
-                        let ty = prev_type;
-                        quote! {
-                            let raw = <#ty as argser::FromArgs>::arguments();
-                            let extend_iter = raw
-                                .into_iter()
-                                .map(|mut raw| {
-                                    raw.name = format!("{}.{}.{}", #name, "{name}", raw.name);
-                                    raw.required = false;
-                                    raw
-                                });
-                            args.extend(extend_iter);
-                        }
-                    }
-                    _ => quote! {},
-                };
-
-                populate_block.extend(block);
+            FieldValue::Map { .. } | FieldValue::List { .. } => {
+                populate_block.extend(field.value.arguments_block(
+                    name,
+                    separator,
+                    &field.inner_ty,
+                    &nested_rule,
+                ));
             }
+            FieldValue::Skip | FieldValue::Computed(_) => {}
         };
     }
 
+    populate_block
+}
+
+fn impl_arguments(fields: &[ParseField]) -> TokenStream {
+    let renamed_block = renamed_decls(fields.iter());
+    let populate_block = populate_arguments(fields);
+
     quote! {
         fn arguments() -> Vec<argser::ArgumentDetail> {
+            <Self as argser::FromArgs>::arguments_with_rule(None)
+        }
+
+        fn arguments_with_rule(rule: Option<argser::RenameRule>) -> Vec<argser::ArgumentDetail> {
+            let _ = rule;
+            #renamed_block
+
             let mut args = Vec::new();
 
             #populate_block
@@ -313,30 +1140,270 @@ fn impl_arguments(_input: &syn::ItemStruct, fields: &[ParseField]) -> TokenStrea
     }
 }
 
-fn impl_from_args(input: &syn::ItemStruct, fields: &[ParseField]) -> TokenStream {
+/// Generates the Declarations of the Names of the given Fields that are
+/// renamed by a Naming-Convention, where `rule` is the inherited one
+fn renamed_decls<'a, I>(fields: I) -> TokenStream
+where
+    I: Iterator<Item = &'a ParseField>,
+{
+    let mut result = quote! {};
+    for field in fields {
+        result.extend(field.renamed_decl());
+    }
+    result
+}
+
+/// Generates the Check for colliding Arguments, which compares the Names of
+/// all Arguments if any Field is flattened and otherwise only checks the
+/// nested Structs, so that Structs without any nested Structs keep the
+/// Default
+fn impl_check_arguments(fields: &[ParseField]) -> TokenStream {
+    let check_block = if has_flatten(fields) {
+        quote! {
+            argser::check_collisions(&<Self as argser::FromArgs>::arguments())
+        }
+    } else {
+        let mut nested_block = quote! {};
+        let mut nested_fields = Vec::new();
+        for field in fields {
+            let ty = match &field.value {
+                FieldValue::SubCategory => &field.ty,
+                FieldValue::Map { .. } | FieldValue::List { .. }
+                    if matches!(field.value.leaf(), FieldValue::SubCategory) =>
+                {
+                    &field.inner_ty
+                }
+                _ => continue,
+            };
+            let separator = field.separator;
+            let pattern = field.value.pattern(field.name(), separator);
+            nested_block.extend(quote! {
+                <#ty as argser::FromArgs>::check_arguments()
+                    .map_err(|e| e.with_prefix_separated(#pattern, #separator))?;
+            });
+            nested_fields.push(field);
+        }
+        if nested_block.is_empty() {
+            return quote! {};
+        }
+
+        // The Check does not depend on any inherited Naming-Convention
+        let renamed_block = renamed_decls(nested_fields.into_iter());
+        quote! {
+            let rule: Option<argser::RenameRule> = None;
+            let _ = rule;
+            #renamed_block
+
+            #nested_block
+            Ok(())
+        }
+    };
+
+    quote! {
+        fn check_arguments() -> Result<(), argser::ParseError> {
+            #check_block
+        }
+    }
+}
+
+/// Generates the Implementation of `validate_argument`, which parses the
+/// Value as the Type of the Field with the given Name, while delegating to
+/// the SubCategories for Names that start with their Prefix
+fn impl_validate(fields: &[ParseField]) -> TokenStream {
+    let mut validate_block = quote! {};
+    for field in fields {
+        let name = field.name();
+        let separator = field.separator;
+        let ty = &field.ty;
+        let nested_rule = field.nested_rule();
+
+        match &field.value {
+            FieldValue::Primitive => validate_block.extend(quote! {
+                if name == #name {
+                    return Some(
+                        <#ty as argser::ParseFromArgs>::parse_value(value.clone()).map(|_| ()),
+                    );
+                }
+            }),
+            FieldValue::Flatten { prefix: None } => validate_block.extend(quote! {
+                if let Some(result) = <#ty as argser::FromArgs>::validate_argument_with_rule(name, value, #nested_rule) {
+                    return Some(result);
+                }
+            }),
+            FieldValue::SubCategory | FieldValue::Flatten { .. } => {
+                let name = match &field.value {
+                    FieldValue::Flatten {
+                        prefix: Some(prefix),
+                    } => quote! { #prefix },
+                    _ => name,
+                };
+
+                validate_block.extend(quote! {
+                    let nested = name
+                        .strip_prefix(#name)
+                        .and_then(|rest| rest.strip_prefix(#separator));
+                    if let Some(nested) = nested {
+                        if let Some(result) = <#ty as argser::FromArgs>::validate_argument_with_rule(nested, value, #nested_rule) {
+                            return Some(result);
+                        }
+                    }
+                });
+            }
+            FieldValue::Map { .. }
+            | FieldValue::List { .. }
+            | FieldValue::Skip
+            | FieldValue::Computed(_) => {}
+        };
+    }
+
+    // The provided Implementation already handles Structs without any
+    // Fields that could be validated
+    if validate_block.is_empty() {
+        return quote! {};
+    }
+
+    // Only the Names of the Fields that are validated directly are needed
+    let renamed_block = renamed_decls(
+        fields
+            .iter()
+            .filter(|f| matches!(f.value, FieldValue::Primitive | FieldValue::SubCategory)),
+    );
+
+    quote! {
+        fn validate_argument(
+            name: &str,
+            value: &argser::Value,
+        ) -> Option<Result<(), argser::ParseError>> {
+            <Self as argser::FromArgs>::validate_argument_with_rule(name, value, None)
+        }
+
+        fn validate_argument_with_rule(
+            name: &str,
+            value: &argser::Value,
+            rule: Option<argser::RenameRule>,
+        ) -> Option<Result<(), argser::ParseError>> {
+            let _ = rule;
+            #renamed_block
+
+            #validate_block
+
+            None
+        }
+    }
+}
+
+/// The Types passed as generic Arguments to the given Type, like `String` and
+/// `u16` for `HashMap<String, u16>`
+fn generic_args(ty: &Type) -> Vec<&Type> {
+    let segment = match ty {
+        Type::Path(path) => match path.path.segments.last() {
+            Some(s) => s,
+            None => return Vec::new(),
+        },
+        _ => return Vec::new(),
+    };
+
+    match &segment.arguments {
+        syn::PathArguments::AngleBracketed(args) => args
+            .args
+            .iter()
+            .filter_map(|arg| match arg {
+                syn::GenericArgument::Type(ty) => Some(ty),
+                _ => None,
+            })
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Whether the given Type mentions any of the given Type-Parameters
+fn uses_params(ty: &Type, params: &[&Ident]) -> bool {
+    fn walk(tokens: TokenStream, params: &[&Ident]) -> bool {
+        tokens.into_iter().any(|token| match token {
+            proc_macro2::TokenTree::Ident(ident) => params.iter().any(|p| **p == ident),
+            proc_macro2::TokenTree::Group(group) => walk(group.stream(), params),
+            _ => false,
+        })
+    }
+
+    walk(ty.to_token_stream(), params)
+}
+
+/// Adds the Bounds needed to parse all the Fields, whose Types depend on
+/// Type-Parameters of the Struct, to the Generics of the Struct
+fn bounded_generics(input: &syn::ItemStruct, fields: &[ParseField]) -> syn::Generics {
+    let mut generics = input.generics.clone();
+    let params: Vec<_> = input.generics.type_params().map(|p| &p.ident).collect();
+    if params.is_empty() {
+        return generics;
+    }
+
+    let where_clause = generics.make_where_clause();
+    for field in fields {
+        if !uses_params(&field.ty, &params) {
+            continue;
+        }
+
+        let inner_ty = &field.inner_ty;
+        let ty = &field.ty;
+        let predicate: Option<syn::WherePredicate> = match field.value.leaf() {
+            FieldValue::Primitive => Some(syn::parse_quote! { #inner_ty: argser::ParseFromArgs }),
+            FieldValue::SubCategory | FieldValue::Flatten { .. } => {
+                Some(syn::parse_quote! { #inner_ty: argser::FromArgs })
+            }
+            FieldValue::Skip => match field.default_func {
+                DefaultValue::None | DefaultValue::Impl => Some(syn::parse_quote! { #ty: Default }),
+                _ => None,
+            },
+            _ => None,
+        };
+        where_clause.predicates.extend(predicate);
+    }
+
+    generics
+}
+
+fn impl_from_args(
+    input: &syn::ItemStruct,
+    fields: &[ParseField],
+    options: &StructOptions,
+) -> TokenStream {
     let name = &input.ident;
+    let generics = bounded_generics(input, fields);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
-    let parse_block = impl_parse(input, fields);
-    let arguments_block = impl_arguments(input, fields);
+    let parse_block = impl_parse(fields, options);
+    let arguments_block = impl_arguments(fields);
+    let check_block = impl_check_arguments(fields);
+    let validate_block = impl_validate(fields);
+    let positional_block = match &input.fields {
+        syn::Fields::Unnamed(_) => quote! {
+            fn positional() -> bool {
+                true
+            }
+        },
+        _ => quote! {},
+    };
 
     quote! {
-        impl argser::FromArgs for #name {
+        impl #impl_generics argser::FromArgs for #name #ty_generics #where_clause {
             #parse_block
 
             #arguments_block
+
+            #check_block
+
+            #validate_block
+
+            #positional_block
         }
     }
 }
 
 fn generate_struct(input: &syn::ItemStruct, fields: &[ParseField]) -> TokenStream {
     let mut inner = input.clone();
-    for field in inner.fields.iter_mut() {
-        let name = field.ident.as_ref().unwrap();
-        let n_type = fields
-            .iter()
-            .find(|nf| nf.ident.eq(name))
-            .map(|nf| nf.ty.clone())
-            .unwrap();
+    for (field, parsed) in inner.fields.iter_mut().zip(fields) {
+        let n_type = parsed.ty.clone();
 
         let new_fields: Vec<_> = field
             .attrs
@@ -361,18 +1428,75 @@ fn generate_struct(input: &syn::ItemStruct, fields: &[ParseField]) -> TokenStrea
     inner.to_token_stream()
 }
 
-fn parse_fields(input: &syn::ItemStruct) -> Vec<ParseField> {
-    input
-        .fields
-        .iter()
-        .map(|field| ParseField::parse(field))
-        .collect()
+/// Parses all the Fields of the Struct, while combining the Errors of all
+/// the Fields so they can be reported at once
+fn parse_fields(input: &syn::ItemStruct, options: &StructOptions) -> syn::Result<Vec<ParseField>> {
+    let mut result = Vec::new();
+    let mut errors: Option<syn::Error> = None;
+
+    for (index, field) in input.fields.iter().enumerate() {
+        match ParseField::parse(index, field, options) {
+            Ok(f) => result.push(f),
+            Err(e) => match &mut errors {
+                Some(errors) => errors.combine(e),
+                None => errors = Some(e),
+            },
+        };
+    }
+
+    match errors {
+        Some(e) => Err(e),
+        None => Ok(result),
+    }
 }
 
-pub fn argser(_attributes: syn::AttributeArgs, input: syn::ItemStruct) -> TokenStream {
-    let fields = parse_fields(&input);
+/// Implements [`ParseFromArgs`] for a Newtype, by simply parsing the single
+/// Field it wraps
+fn impl_newtype(input: &syn::ItemStruct, field: &ParseField) -> TokenStream {
+    let name = &input.ident;
+    let generics = bounded_generics(input, std::slice::from_ref(field));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    quote! {
+        impl #impl_generics argser::ParseFromArgs for #name #ty_generics #where_clause {
+            fn parse(value: Vec<String>) -> Result<Self, argser::ParseError> {
+                argser::ParseFromArgs::parse(value).map(Self)
+            }
 
-    let impl_block = impl_from_args(&input, &fields);
+            fn parse_lenient(
+                value: Vec<String>,
+                skipped: &mut Vec<argser::ParseError>,
+            ) -> Result<Self, argser::ParseError> {
+                argser::ParseFromArgs::parse_lenient(value, skipped).map(Self)
+            }
+
+            fn parse_value(value: argser::Value) -> Result<Self, argser::ParseError> {
+                argser::ParseFromArgs::parse_value(value).map(Self)
+            }
+
+            fn parse_value_lenient(
+                value: argser::Value,
+                skipped: &mut Vec<argser::ParseError>,
+            ) -> Result<Self, argser::ParseError> {
+                argser::ParseFromArgs::parse_value_lenient(value, skipped).map(Self)
+            }
+        }
+    }
+}
+
+pub fn argser(attributes: syn::AttributeArgs, input: syn::ItemStruct) -> TokenStream {
+    let options = match StructOptions::parse(&attributes) {
+        Ok(o) => o,
+        Err(e) => return e.to_compile_error(),
+    };
+    let fields = match parse_fields(&input, &options) {
+        Ok(f) => f,
+        Err(e) => return e.to_compile_error(),
+    };
+    let impl_block = match impl_struct(&input, &fields, &options) {
+        Ok(i) => i,
+        Err(e) => return e.to_compile_error(),
+    };
     let cleaned_up = generate_struct(&input, &fields);
 
     quote! {
@@ -381,3 +1505,51 @@ pub fn argser(_attributes: syn::AttributeArgs, input: syn::ItemStruct) -> TokenS
         #impl_block
     }
 }
+
+pub fn derive(input: syn::ItemStruct) -> TokenStream {
+    let mut attributes = Vec::new();
+    for attr in input.attrs.iter().filter(|a| a.path.is_ident("argser")) {
+        match attr.parse_meta() {
+            Ok(syn::Meta::List(list)) => attributes.extend(list.nested),
+            Ok(other) => {
+                return syn::Error::new_spanned(other, "Expected a List of Attributes")
+                    .to_compile_error()
+            }
+            Err(e) => return e.to_compile_error(),
+        };
+    }
+
+    let options = match StructOptions::parse(&attributes) {
+        Ok(o) => StructOptions {
+            explicit_types: true,
+            ..o
+        },
+        Err(e) => return e.to_compile_error(),
+    };
+    match parse_fields(&input, &options).and_then(|fields| impl_struct(&input, &fields, &options)) {
+        Ok(i) => i,
+        Err(e) => e.to_compile_error(),
+    }
+}
+
+/// Generates the Implementation of either [`FromArgs`] or, for Newtypes,
+/// [`ParseFromArgs`] for the given Struct
+fn impl_struct(
+    input: &syn::ItemStruct,
+    fields: &[ParseField],
+    options: &StructOptions,
+) -> syn::Result<TokenStream> {
+    match &input.fields {
+        syn::Fields::Unnamed(unnamed) if fields.len() == 1 => {
+            let field = &unnamed.unnamed[0];
+            if let Some(attr) = field.attrs.iter().find(|a| a.path.is_ident("argser")) {
+                return Err(syn::Error::new_spanned(
+                    attr,
+                    "The Field of a Newtype can not have any Attributes",
+                ));
+            }
+            Ok(impl_newtype(input, &fields[0]))
+        }
+        _ => Ok(impl_from_args(input, fields, options)),
+    }
+}
@@ -1,6 +1,6 @@
 use std::fmt::Debug;
 
-use proc_macro2::Ident;
+use proc_macro2::{Ident, Span};
 use syn::{parenthesized, Token};
 
 /// A single Argser-Attribute that is applied to a Field
@@ -9,6 +9,18 @@ pub enum FieldAttribute {
     /// The Field is Marked as containing a SubCategory and should therefore
     /// be parsed not as a "Primitive"-Type but rather an entire category
     SubCategory,
+    /// The Field contains a SubCategory, whose Arguments are merged into the
+    /// Namespace of the parent instead of being nested under the Field-Name
+    Flatten,
+    /// The Field is not parsed from the Arguments, but instead initialized
+    /// using its Default-Value
+    Skip,
+    /// The Field is not parsed from the Arguments, but instead computed
+    /// using the given Expression, which can use all the other Fields
+    Compute {
+        /// The Expression that evaluates to the Value of the Field
+        expr: syn::Expr,
+    },
     /// The Field should be parsed using a different Name
     Rename {
         /// The Name to use for parsing
@@ -22,9 +34,126 @@ pub enum FieldAttribute {
     },
     /// The Field should use the Types Default implementation as fallback
     Default_,
-    Map {
-        sub: Option<Ident>,
+    /// The Field should use the given Literal as fallback, which is parsed
+    /// like any other supplied Value
+    DefaultLiteral {
+        /// The textual Form of the Literal
+        value: String,
+    },
+    /// The Field should use the given Expression as fallback
+    DefaultExpr {
+        /// The Expression that evaluates to the Default-Value
+        expr: syn::Expr,
+    },
+    /// The Value of the Field can also be loaded from a File, whose Path is
+    /// given using the `{name}_FILE` or `{name}.file` Argument
+    FileIndirection,
+    /// The Field falls back to the Value of the given Environment-Variable,
+    /// if no Value was supplied by the Providers
+    Env {
+        /// The Name of the Environment-Variable
+        name: String,
     },
+    /// The Default-Value of the Field is also used, if the supplied Value could
+    /// not be parsed, instead of only when no Value was supplied
+    DefaultOnInvalid,
+    /// The Value of the Field is a Secret, which is not displayed when it is
+    /// entered interactively
+    Secret,
+    /// Malformed Entries of the Field are skipped and reported as Warnings,
+    /// instead of causing the Parsing to fail
+    Lenient,
+    /// The Field contains a Map of Values, which are provided using
+    /// Arguments like `{name}.{key}`
+    Map(Container),
+    /// The Field contains a List of Values, which are provided using indexed
+    /// Arguments like `{name}.0`
+    List(Container),
+}
+
+/// The Configuration for a Field that contains a Map or List of Values
+#[derive(Debug, Default)]
+pub struct Container {
+    /// What is stored in the Map/List, which is either a SubCategory or
+    /// another Map/List, or None for Primitive Values
+    pub inner: Option<Box<FieldAttribute>>,
+    /// The Type for the Keys of a Map, which defaults to String
+    pub key: Option<syn::Type>,
+    /// Whether a BTreeMap should be used instead of a HashMap
+    pub btree: bool,
+}
+
+impl Container {
+    fn parse_options(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let mut result = Self::default();
+        if !input.peek(syn::token::Paren) {
+            return Ok(result);
+        }
+
+        let content;
+        parenthesized!(content in input);
+
+        while !content.is_empty() {
+            let ident: Ident = content.fork().parse()?;
+            match ident.to_string().as_ref() {
+                "key" => {
+                    content.parse::<Ident>()?;
+                    content.parse::<Token![=]>()?;
+                    result.key = Some(content.parse()?);
+                }
+                "btree" => {
+                    content.parse::<Ident>()?;
+                    result.btree = true;
+                }
+                _ => {
+                    let inner: FieldAttribute = content.parse()?;
+                    match inner {
+                        FieldAttribute::SubCategory
+                        | FieldAttribute::Map(_)
+                        | FieldAttribute::List(_) => {
+                            result.inner = Some(Box::new(inner));
+                        }
+                        _ => {
+                            return Err(syn::Error::new(
+                                ident.span(),
+                                "Unsupported Content for a Map or List",
+                            ))
+                        }
+                    };
+                }
+            };
+
+            if content.is_empty() {
+                break;
+            }
+            content.parse::<Token![,]>()?;
+        }
+
+        Ok(result)
+    }
+}
+
+impl FieldAttribute {
+    /// The Name of the Attribute, as it is written by the User
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::SubCategory => "subcategory",
+            Self::Flatten => "flatten",
+            Self::Skip => "skip",
+            Self::Compute { .. } => "compute",
+            Self::Rename { .. } => "rename",
+            Self::DefaultFunc { .. } => "default_func",
+            Self::Default_ | Self::DefaultLiteral { .. } => "default",
+            Self::DefaultExpr { .. } => "default_expr",
+            Self::FileIndirection => "file_indirection",
+            Self::Env { .. } => "env",
+            Self::DefaultOnInvalid => "default_on_invalid",
+            Self::Lenient => "lenient",
+            Self::Secret => "secret",
+            Self::Map(_) => "map",
+            Self::List(_) => "list",
+        }
+    }
 }
 
 impl syn::parse::Parse for FieldAttribute {
@@ -34,6 +163,15 @@ impl syn::parse::Parse for FieldAttribute {
 
         match ident_str.as_ref() {
             "subcategory" => Ok(FieldAttribute::SubCategory),
+            "flatten" => Ok(FieldAttribute::Flatten),
+            "skip" => Ok(FieldAttribute::Skip),
+            "compute" => {
+                input.parse::<Token![=]>()?;
+
+                let expr: syn::Expr = input.parse()?;
+
+                Ok(FieldAttribute::Compute { expr })
+            }
             "rename" => {
                 let content;
                 parenthesized!(content in input);
@@ -52,35 +190,76 @@ impl syn::parse::Parse for FieldAttribute {
 
                 Ok(FieldAttribute::DefaultFunc { func: value })
             }
-            "default" => Ok(FieldAttribute::Default_),
-            "map" => {
-                let lookahead = input.lookahead1();
-                let ty: Option<Ident> = if lookahead.peek(syn::token::Paren) {
-                    let content;
-                    parenthesized!(content in input);
-
-                    match content.parse() {
-                        Ok(t) => Some(t),
-                        Err(_) => None,
+            "default" if input.peek(Token![=]) => {
+                input.parse::<Token![=]>()?;
+
+                let lit: syn::Lit = input.parse()?;
+                let value = match &lit {
+                    syn::Lit::Str(l) => l.value(),
+                    syn::Lit::Char(l) => l.value().to_string(),
+                    syn::Lit::Int(l) => l.base10_digits().to_owned(),
+                    syn::Lit::Float(l) => l.base10_digits().to_owned(),
+                    syn::Lit::Bool(l) => l.value.to_string(),
+                    _ => {
+                        return Err(syn::Error::new(
+                            lit.span(),
+                            "Unsupported Literal for a Default-Value",
+                        ))
                     }
-                } else {
-                    None
                 };
 
-                Ok(FieldAttribute::Map { sub: ty })
+                Ok(FieldAttribute::DefaultLiteral { value })
+            }
+            "default" => Ok(FieldAttribute::Default_),
+            "default_expr" => {
+                input.parse::<Token![=]>()?;
+
+                let expr: syn::Expr = input.parse()?;
+
+                Ok(FieldAttribute::DefaultExpr { expr })
             }
-            _ => Err(syn::Error::new(ident.span(), "Unknown Attribute")),
+            "env" => {
+                input.parse::<Token![=]>()?;
+
+                let value: syn::LitStr = input.parse()?;
+
+                Ok(FieldAttribute::Env {
+                    name: value.value(),
+                })
+            }
+            "file_indirection" => Ok(FieldAttribute::FileIndirection),
+            "lenient" => Ok(FieldAttribute::Lenient),
+            "secret" => Ok(FieldAttribute::Secret),
+            "default_on_invalid" => Ok(FieldAttribute::DefaultOnInvalid),
+            "map" => Ok(FieldAttribute::Map(Container::parse_options(input)?)),
+            "list" => Ok(FieldAttribute::List(Container::parse_options(input)?)),
+            _ => Err(syn::Error::new(
+                ident.span(),
+                format!("Unknown Attribute `{}`", ident_str),
+            )),
         }
     }
 }
 
+/// A FieldAttribute together with the Span it was written at, which is
+/// used to report Errors for the Attribute
 #[derive(Debug)]
-struct FieldAttributeList(Vec<FieldAttribute>);
+pub struct SpannedAttribute {
+    pub span: Span,
+    pub attr: FieldAttribute,
+}
+
+#[derive(Debug)]
+struct FieldAttributeList(Vec<SpannedAttribute>);
 
 impl syn::parse::Parse for FieldAttributeList {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
         let mut result = Vec::new();
-        let items = input.parse_terminated::<FieldAttribute, Token![,]>(FieldAttribute::parse)?;
+        let items = input.parse_terminated::<SpannedAttribute, Token![,]>(|input| {
+            let span = input.span();
+            let attr = FieldAttribute::parse(input)?;
+            Ok(SpannedAttribute { span, attr })
+        })?;
 
         for item in items {
             result.push(item);
@@ -90,7 +269,7 @@ impl syn::parse::Parse for FieldAttributeList {
     }
 }
 
-pub fn parse_attributes(attributes: &[syn::Attribute]) -> Vec<FieldAttribute> {
+pub fn parse_attributes(attributes: &[syn::Attribute]) -> syn::Result<Vec<SpannedAttribute>> {
     let mut result = Vec::new();
 
     for attr in attributes {
@@ -100,12 +279,7 @@ pub fn parse_attributes(attributes: &[syn::Attribute]) -> Vec<FieldAttribute> {
                     continue;
                 }
 
-                let inner: FieldAttributeList = match attr.parse_args() {
-                    Ok(i) => i,
-                    Err(_) => {
-                        continue;
-                    }
-                };
+                let inner: FieldAttributeList = attr.parse_args()?;
 
                 result.extend(inner.0);
             }
@@ -113,5 +287,5 @@ pub fn parse_attributes(attributes: &[syn::Attribute]) -> Vec<FieldAttribute> {
         };
     }
 
-    result
+    Ok(result)
 }
@@ -0,0 +1,177 @@
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use syn::{Lit, Meta, NestedMeta};
+
+/// The Naming-Convention that should be applied to the Names of all the
+/// Fields of a Struct, which is turned into the corresponding
+/// `argser::RenameRule` that renames the Fields while parsing
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RenameRule {
+    /// `lowercase`
+    Lower,
+    /// `UPPERCASE`
+    Upper,
+    /// `PascalCase`
+    Pascal,
+    /// `camelCase`
+    Camel,
+    /// `snake_case`
+    Snake,
+    /// `SCREAMING_SNAKE_CASE`
+    ScreamingSnake,
+    /// `kebab-case`
+    Kebab,
+    /// `SCREAMING-KEBAB-CASE`
+    ScreamingKebab,
+}
+
+impl RenameRule {
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "lowercase" => Some(Self::Lower),
+            "UPPERCASE" => Some(Self::Upper),
+            "PascalCase" => Some(Self::Pascal),
+            "camelCase" => Some(Self::Camel),
+            "snake_case" => Some(Self::Snake),
+            "SCREAMING_SNAKE_CASE" => Some(Self::ScreamingSnake),
+            "kebab-case" => Some(Self::Kebab),
+            "SCREAMING-KEBAB-CASE" => Some(Self::ScreamingKebab),
+            _ => None,
+        }
+    }
+}
+
+/// Generates the Path to the corresponding `argser::RenameRule`
+impl ToTokens for RenameRule {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let variant = match self {
+            Self::Lower => quote! { Lower },
+            Self::Upper => quote! { Upper },
+            Self::Pascal => quote! { Pascal },
+            Self::Camel => quote! { Camel },
+            Self::Snake => quote! { Snake },
+            Self::ScreamingSnake => quote! { ScreamingSnake },
+            Self::Kebab => quote! { Kebab },
+            Self::ScreamingKebab => quote! { ScreamingKebab },
+        };
+        tokens.extend(quote! { argser::RenameRule::#variant });
+    }
+}
+
+/// The Configuration passed to the `argser`-Attribute on a Struct itself
+#[derive(Debug, Default)]
+pub struct StructOptions {
+    /// The Naming-Convention for Fields that are not explicitly renamed
+    pub rename_all: Option<RenameRule>,
+    /// The Prefix that is prepended to the Names of all the Fields
+    pub prefix: Option<String>,
+    /// The Character that separates the Segments of the Names, if it is not
+    /// the default `.`
+    pub separator: Option<char>,
+    /// Whether the Types of Map- and List-Fields are already written out in
+    /// full, which is the case for the Derive-Macro
+    pub explicit_types: bool,
+}
+
+impl StructOptions {
+    pub fn parse(attributes: &[NestedMeta]) -> syn::Result<Self> {
+        let mut result = Self::default();
+
+        for attr in attributes {
+            let pair = match attr {
+                NestedMeta::Meta(Meta::NameValue(pair)) => pair,
+                other => return Err(syn::Error::new_spanned(other, "Unknown Attribute")),
+            };
+            let value = match &pair.lit {
+                Lit::Str(value) => value,
+                other => return Err(syn::Error::new_spanned(other, "Expected a String")),
+            };
+
+            if pair.path.is_ident("rename_all") {
+                let rule = RenameRule::from_name(&value.value())
+                    .ok_or_else(|| syn::Error::new_spanned(value, "Unknown Naming-Convention"))?;
+                result.rename_all = Some(rule);
+            } else if pair.path.is_ident("prefix") {
+                result.prefix = Some(value.value());
+            } else if pair.path.is_ident("separator") {
+                let raw = value.value();
+                let mut chars = raw.chars();
+                let separator = match (chars.next(), chars.next()) {
+                    (Some('"'), None) => return Err(syn::Error::new_spanned(
+                        value,
+                        "The Separator can not be a Quote, as those are used for quoted Segments",
+                    )),
+                    (Some(c), None) => c,
+                    _ => {
+                        return Err(syn::Error::new_spanned(
+                            value,
+                            "The Separator has to be a single Character",
+                        ))
+                    }
+                };
+                result.separator = Some(separator);
+            } else {
+                return Err(syn::Error::new_spanned(
+                    &pair.path,
+                    format!("Unknown Attribute `{}`", pair.path.to_token_stream()),
+                ));
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// The Character that separates the Segments of the Names
+    pub fn separator(&self) -> char {
+        self.separator.unwrap_or('.')
+    }
+
+    /// Determines the Name of the Argument for the Field with the given Name,
+    /// without the Naming-Convention, which is only applied while parsing and
+    /// never to explicitly renamed Fields
+    pub fn arg_name(&self, field_name: &str, rename: Option<String>) -> String {
+        let name = rename.unwrap_or_else(|| field_name.to_owned());
+
+        match &self.prefix {
+            Some(prefix) => format!("{}{}{}", prefix, self.separator(), name),
+            None => name,
+        }
+    }
+}
+
+/// Splits the given Name into its Segments, while removing the Quotes around
+/// quoted Segments, which mirrors `argser::KeyFormat::split` so the Segments
+/// can already be determined at compile-time.
+///
+/// Both are checked against the same Cases in `argser/tests/separator.rs`,
+/// so any Change to the Rules has to be made in both places
+pub fn split_name(name: &str, separator: char) -> Vec<String> {
+    let mut result = Vec::new();
+    let mut segment = String::new();
+    let mut at_start = true;
+
+    let mut chars = name.chars().peekable();
+    while let Some(c) = chars.next() {
+        if at_start && c == '"' {
+            while let Some(c) = chars.next() {
+                match c {
+                    '"' => break,
+                    '\\' if matches!(chars.peek(), Some('"') | Some('\\')) => {
+                        segment.extend(chars.next());
+                    }
+                    c => segment.push(c),
+                };
+            }
+            at_start = false;
+        } else if c == separator {
+            result.push(std::mem::take(&mut segment));
+            at_start = true;
+        } else {
+            segment.push(c);
+            at_start = false;
+        }
+    }
+    result.push(segment);
+
+    result
+}
@@ -16,3 +16,17 @@ pub fn argser(
 
     argser::argser(attr, input).into()
 }
+
+/// This will implement the `argser::FromArgs` trait for the Struct it is
+/// applied on, just like the [`macro@argser`]-Attribute, but without modifying
+/// the Struct itself.
+///
+/// The Configuration is done using the same `#[argser(...)]`-Attributes,
+/// except that the Types of `map` and `list` Fields have to be written out in
+/// full, like `HashMap<String, u16>` or `Vec<u16>`
+#[proc_macro_derive(FromArgs, attributes(argser))]
+pub fn derive_from_args(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input: syn::ItemStruct = syn::parse_macro_input!(input);
+
+    argser::derive(input).into()
+}
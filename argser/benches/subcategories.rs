@@ -0,0 +1,136 @@
+//! Compares parsing nested SubCategories from the Tree of Arguments with the
+//! previous Approach of scanning and cloning all the Arguments for every
+//! SubCategory, while an increasing Number of unrelated Arguments is
+//! present, like the Variables injected by the [`Env`](argser::provider::Env)
+//! Provider.
+
+use std::{collections::HashMap, hint::black_box};
+
+use argser::{argser, provider::Fixed, ParseFromArgs};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+
+#[argser]
+#[allow(dead_code)]
+struct Limits {
+    cpu: u16,
+    memory: u16,
+}
+
+#[argser]
+#[allow(dead_code)]
+struct Section {
+    host: String,
+    port: u16,
+    #[argser(subcategory)]
+    limits: Limits,
+}
+
+#[argser]
+#[allow(dead_code)]
+struct Config {
+    #[argser(subcategory)]
+    api: Section,
+    #[argser(subcategory)]
+    db: Section,
+    #[argser(subcategory)]
+    cache: Section,
+    #[argser(subcategory)]
+    queue: Section,
+    #[argser(subcategory)]
+    search: Section,
+}
+
+const SECTIONS: &[&str] = &["api", "db", "cache", "queue", "search"];
+
+fn provider(noise: usize) -> Fixed {
+    let mut result = Fixed::empty();
+    for section in SECTIONS {
+        result.add_arg(format!("{}.host", section), "localhost");
+        result.add_arg(format!("{}.port", section), "8080");
+        result.add_arg(format!("{}.limits.cpu", section), "4");
+        result.add_arg(format!("{}.limits.memory", section), "512");
+    }
+    for i in 0..noise {
+        result.add_arg(format!("unrelated_variable_{}", i), "value");
+    }
+    result
+}
+
+/// Collects the Arguments starting with `{prefix}.` into a new Map, which is
+/// what the generated Code used to do for every SubCategory
+fn scan(args: &HashMap<String, Vec<String>>, prefix: &str) -> HashMap<String, Vec<String>> {
+    let prefix = format!("{}.", prefix);
+    let mut result = HashMap::new();
+    for (key, value) in args.iter() {
+        if let Some(n_key) = key.strip_prefix(&prefix) {
+            result.insert(n_key.to_owned(), value.to_owned());
+        }
+    }
+    result
+}
+
+fn primitive<T>(args: &HashMap<String, Vec<String>>, name: &str) -> T
+where
+    T: ParseFromArgs,
+{
+    T::parse(args.get(name).cloned().unwrap_or_default()).unwrap()
+}
+
+fn scan_limits(args: HashMap<String, Vec<String>>) -> Limits {
+    Limits {
+        cpu: primitive(&args, "cpu"),
+        memory: primitive(&args, "memory"),
+    }
+}
+
+fn scan_section(args: HashMap<String, Vec<String>>) -> Section {
+    Section {
+        host: primitive(&args, "host"),
+        port: primitive(&args, "port"),
+        limits: scan_limits(scan(&args, "limits")),
+    }
+}
+
+fn scan_config(args: HashMap<String, Vec<String>>) -> Config {
+    Config {
+        api: scan_section(scan(&args, "api")),
+        db: scan_section(scan(&args, "db")),
+        cache: scan_section(scan(&args, "cache")),
+        queue: scan_section(scan(&args, "queue")),
+        search: scan_section(scan(&args, "search")),
+    }
+}
+
+fn subcategories(c: &mut Criterion) {
+    let mut group = c.benchmark_group("subcategories");
+
+    for noise in [10, 100, 1000] {
+        let provider = provider(noise);
+
+        group.bench_with_input(BenchmarkId::new("tree", noise), &provider, |b, provider| {
+            b.iter(|| {
+                let config: Config = argser::parse_args_from_providers(&[provider]).unwrap();
+                black_box(config)
+            })
+        });
+
+        group.bench_with_input(
+            BenchmarkId::new("prefix_scan", noise),
+            &provider,
+            |b, provider| {
+                b.iter(|| {
+                    let mut args: HashMap<String, Vec<String>> = HashMap::new();
+                    for (key, value) in argser::ArgProvider::get_args(provider) {
+                        args.entry(key).or_default().push(value);
+                    }
+                    black_box(scan_config(args))
+                })
+            },
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, subcategories);
+criterion_main!(benches);
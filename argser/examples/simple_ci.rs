@@ -2,7 +2,7 @@ use argser::argser;
 
 #[argser]
 #[derive(Debug)]
-struct CLI {
+struct Cli {
     name: String,
     #[argser(subcategory, rename("other_name"))]
     sub: SubCategory,
@@ -25,7 +25,8 @@ fn default_names() -> Vec<String> {
 }
 
 pub fn main() {
-    let cli: CLI = argser::parse_args_from_providers(&[&argser::provider::Cli::new()]).unwrap();
+    let cli: Cli = argser::parse_args_from_providers(&[&argser::provider::Cli::new()]).unwrap();
 
     println!("Cli: {:?}", cli);
+    println!("Other: {}", cli.sub.other);
 }
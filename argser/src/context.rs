@@ -0,0 +1,108 @@
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+
+use crate::{Environment, KeyFormat, ParseError, ProcessEnv, RenameRule};
+
+/// A Problem with some Argument that did not cause the Parsing to fail, like
+/// an Entry that was skipped by a Field marked as `lenient`
+#[derive(Debug, PartialEq)]
+pub struct Warning {
+    /// The full Name of the Argument
+    pub name: String,
+    /// The Error that was encountered for the Argument
+    pub error: ParseError,
+}
+
+/// The State that is passed along while parsing a single Struct and all of
+/// its SubCategories
+#[derive(Default)]
+pub struct ParseContext {
+    warnings: Vec<Warning>,
+    env: Option<Box<dyn Environment>>,
+    key_format: Option<KeyFormat>,
+    rename_rule: Option<RenameRule>,
+}
+
+impl ParseContext {
+    /// Creates a new empty Context
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records a Warning for the Argument with the given Name
+    pub fn warn<N>(&mut self, name: N, error: ParseError)
+    where
+        N: Into<String>,
+    {
+        self.warnings.push(Warning {
+            name: name.into(),
+            error,
+        });
+    }
+
+    /// All the Warnings that were recorded so far
+    pub fn warnings(&self) -> &[Warning] {
+        &self.warnings
+    }
+
+    /// Removes and returns all the Warnings that were recorded so far
+    pub fn take_warnings(&mut self) -> Vec<Warning> {
+        std::mem::take(&mut self.warnings)
+    }
+
+    /// Updates the Environment used for Fields that are bound to an
+    /// Environment-Variable, which defaults to [`ProcessEnv`]
+    pub fn set_env<E>(&mut self, nvalue: E)
+    where
+        E: Environment + 'static,
+    {
+        self.env = Some(Box::new(nvalue));
+    }
+
+    /// Loads the Value of the given Environment-Variable from the configured
+    /// Environment
+    pub fn env_var(&self, name: &str) -> Option<String> {
+        match &self.env {
+            Some(env) => env.var(name),
+            None => ProcessEnv.var(name),
+        }
+    }
+
+    /// The Format used to split the Names of the provided Arguments, if it
+    /// was configured explicitly, otherwise the Format of the parsed Type,
+    /// see [`FromArgs::key_format`](crate::FromArgs::key_format), is used
+    pub fn key_format(&self) -> Option<KeyFormat> {
+        self.key_format
+    }
+
+    /// Updates the Format used to split the Names of the provided Arguments.
+    ///
+    /// The Names of the Fields are still split using the Separator configured
+    /// on their Struct, so the Separator should not appear in those Names
+    pub fn set_key_format(&mut self, nvalue: KeyFormat) {
+        self.key_format = Some(nvalue);
+    }
+
+    /// The Naming-Convention that is inherited by the Struct currently being
+    /// parsed, which is used for its Fields if it does not configure its own
+    pub fn rename_rule(&self) -> Option<RenameRule> {
+        self.rename_rule
+    }
+
+    /// Updates the inherited Naming-Convention, which is done by the
+    /// generated Code before parsing the SubCategories of a Struct that
+    /// configures its own
+    pub fn set_rename_rule(&mut self, nvalue: Option<RenameRule>) {
+        self.rename_rule = nvalue;
+    }
+}
+
+impl Debug for ParseContext {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("ParseContext")
+            .field("warnings", &self.warnings)
+            .field("custom_env", &self.env.is_some())
+            .field("key_format", &self.key_format)
+            .field("rename_rule", &self.rename_rule)
+            .finish()
+    }
+}
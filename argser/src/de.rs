@@ -0,0 +1,578 @@
+//! The Bridge to [`serde`], which allows any Type implementing
+//! [`Deserialize`](serde::Deserialize) to be loaded from the same Providers
+//! as Types implementing [`FromArgs`](crate::FromArgs).
+//!
+//! The Arguments of all the Providers are merged into a single
+//! [`Value`]-Tree, where dotted Names are mapped to nested Structs, repeated
+//! Arguments to Sequences and Strings are parsed into Numbers and Booleans
+//! as needed.
+
+use std::{collections::BTreeMap, convert::TryFrom, fmt};
+
+use serde::de::{self, DeserializeOwned, IntoDeserializer, Visitor};
+
+use crate::{ArgProvider, KeyFormat, ParseError, Value};
+
+/// This will load all the Arguments from the given Providers and then
+/// deserialize an instance of `T` from them
+pub fn from_providers<T>(providers: &[&dyn ArgProvider]) -> Result<T, ParseError>
+where
+    T: DeserializeOwned,
+{
+    from_providers_with_format(providers, &KeyFormat::default())
+}
+
+/// The same as [`from_providers`], but splits and normalizes the Names of
+/// the Arguments according to the given Format.
+///
+/// As the Fields of `T` are not known up front, every Key is normalized,
+/// including the Keys of Maps, and normalizing two supplied Keys to the same
+/// Key always results in [`ParseError::AmbiguousArgument`]
+pub fn from_providers_with_format<T>(
+    providers: &[&dyn ArgProvider],
+    format: &KeyFormat,
+) -> Result<T, ParseError>
+where
+    T: DeserializeOwned,
+{
+    let value = crate::collect_values(providers, format, None)?;
+
+    from_value_with_format(&value, format)
+}
+
+/// Deserializes an instance of `T` from the given Tree of Arguments
+pub fn from_value<T>(value: &Value) -> Result<T, ParseError>
+where
+    T: DeserializeOwned,
+{
+    from_value_with_format(value, &KeyFormat::default())
+}
+
+/// The same as [`from_value`], but uses the Separator of the given Format
+/// for the Names of the Arguments in Errors
+pub fn from_value_with_format<T>(value: &Value, format: &KeyFormat) -> Result<T, ParseError>
+where
+    T: DeserializeOwned,
+{
+    T::deserialize(Deserializer::with_format(value, format)).map_err(|e| e.0)
+}
+
+/// The Error used while deserializing, which wraps the [`ParseError`] that
+/// is eventually returned
+#[derive(Debug, PartialEq)]
+pub struct Error(ParseError);
+
+impl Error {
+    /// Attaches the Name of the Argument, at which the Error occurred, to
+    /// the Error, which is joined with the Names already in the Error using
+    /// the Separator of the given Format
+    fn at(self, name: &str, format: &KeyFormat) -> Self {
+        match self.0 {
+            ParseError::InvalidValue | ParseError::MissingValue | ParseError::Custom(_) => {
+                Self(ParseError::InvalidArgument {
+                    name: name.to_owned(),
+                    error: Box::new(self.0),
+                })
+            }
+            other => Self(other.with_prefix_separated(name, format.separator())),
+        }
+    }
+}
+
+impl From<Error> for ParseError {
+    fn from(e: Error) -> Self {
+        e.0
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self.0)
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl de::Error for Error {
+    fn custom<T>(msg: T) -> Self
+    where
+        T: fmt::Display,
+    {
+        Self(ParseError::Custom(msg.to_string()))
+    }
+
+    fn missing_field(field: &'static str) -> Self {
+        Self(ParseError::MissingParam {
+            name: field.to_owned(),
+        })
+    }
+}
+
+/// The [`serde::Deserializer`] for a Tree of Arguments
+pub struct Deserializer<'de> {
+    value: &'de Value,
+    format: KeyFormat,
+}
+
+impl<'de> Deserializer<'de> {
+    /// Creates a new Deserializer for the given Tree of Arguments
+    pub fn new(value: &'de Value) -> Self {
+        Self::with_format(value, &KeyFormat::default())
+    }
+
+    /// Creates a new Deserializer for the given Tree of Arguments, which
+    /// uses the Separator of the given Format for the Names in Errors
+    pub fn with_format(value: &'de Value, format: &KeyFormat) -> Self {
+        Self {
+            value,
+            format: *format,
+        }
+    }
+
+    /// Creates a Deserializer for a Part of the Tree, using the same Format
+    fn nested(&self, value: &'de Value) -> Self {
+        Self::with_format(value, &self.format)
+    }
+
+    /// The first Scalar contained in the Value, which is what is used for
+    /// all the Types that only ever consider a single Value
+    fn first(&self) -> Result<&'de Value, Error> {
+        first(self.value).ok_or(Error(ParseError::MissingValue))
+    }
+
+    /// Parses the first Scalar contained in the Value using the given
+    /// Function for Integers and [`str::parse`] for Strings
+    fn parse_scalar<T, F>(&self, from_integer: F) -> Result<T, Error>
+    where
+        T: std::str::FromStr,
+        F: Fn(i64) -> Option<T>,
+    {
+        let result = match self.first()? {
+            Value::Integer(v) => from_integer(*v),
+            Value::String(v) => v.parse().ok(),
+            _ => None,
+        };
+        result.ok_or(Error(ParseError::InvalidValue))
+    }
+
+    /// The Entries of the Value when it is used as a Sequence, which are
+    /// the Entries of an Array or of a Table with numeric Keys
+    fn entries(&self) -> Result<Vec<&'de Value>, Error> {
+        match self.value {
+            Value::Array(v) => Ok(v.iter().collect()),
+            Value::Table(t) => {
+                if let Some(own) = self.value.own() {
+                    return self.nested(own).entries();
+                }
+
+                let mut entries = BTreeMap::new();
+                for (key, value) in t {
+                    let index: usize = key
+                        .parse()
+                        .map_err(|_| Error(ParseError::InvalidIndex { name: key.clone() }))?;
+                    entries.insert(index, value);
+                }
+
+                let mut result = Vec::with_capacity(entries.len());
+                for (expected, (index, value)) in entries.into_iter().enumerate() {
+                    if index != expected {
+                        return Err(Error(ParseError::MissingIndex {
+                            name: expected.to_string(),
+                        }));
+                    }
+                    result.push(value);
+                }
+                Ok(result)
+            }
+            other => Ok(vec![other]),
+        }
+    }
+}
+
+/// The first Scalar contained in the given Value
+fn first(value: &Value) -> Option<&Value> {
+    match value {
+        Value::Array(v) => v.iter().find_map(first),
+        Value::Table(_) => value.own().and_then(first),
+        other => Some(other),
+    }
+}
+
+macro_rules! deserialize_number {
+    ($method:ident, $visit:ident, $ty:ty) => {
+        fn $method<V>(self, visitor: V) -> Result<V::Value, Error>
+        where
+            V: Visitor<'de>,
+        {
+            let value: $ty = self.parse_scalar(|v| <$ty>::try_from(v).ok())?;
+            visitor.$visit(value)
+        }
+    };
+}
+
+impl<'de> de::Deserializer<'de> for Deserializer<'de> {
+    type Error = Error;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self.value {
+            Value::String(v) => visitor.visit_borrowed_str(v),
+            Value::Bool(v) => visitor.visit_bool(*v),
+            Value::Integer(v) => visitor.visit_i64(*v),
+            Value::Float(v) => visitor.visit_f64(*v),
+            Value::Array(v) if v.len() == 1 => self.nested(&v[0]).deserialize_any(visitor),
+            Value::Array(_) => self.deserialize_seq(visitor),
+            Value::Table(_) => self.deserialize_map(visitor),
+        }
+    }
+
+    fn deserialize_bool<V>(self, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self.first()? {
+            Value::Bool(v) => visitor.visit_bool(*v),
+            Value::String(v) if v == "true" => visitor.visit_bool(true),
+            Value::String(v) if v == "false" => visitor.visit_bool(false),
+            _ => Err(Error(ParseError::InvalidValue)),
+        }
+    }
+
+    deserialize_number!(deserialize_i8, visit_i8, i8);
+    deserialize_number!(deserialize_i16, visit_i16, i16);
+    deserialize_number!(deserialize_i32, visit_i32, i32);
+    deserialize_number!(deserialize_i64, visit_i64, i64);
+    deserialize_number!(deserialize_u8, visit_u8, u8);
+    deserialize_number!(deserialize_u16, visit_u16, u16);
+    deserialize_number!(deserialize_u32, visit_u32, u32);
+    deserialize_number!(deserialize_u64, visit_u64, u64);
+
+    fn deserialize_f32<V>(self, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self.first()? {
+            Value::Float(v) => visitor.visit_f32(*v as f32),
+            _ => visitor.visit_f32(self.parse_scalar(|v| Some(v as f32))?),
+        }
+    }
+
+    fn deserialize_f64<V>(self, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self.first()? {
+            Value::Float(v) => visitor.visit_f64(*v),
+            _ => visitor.visit_f64(self.parse_scalar(|v| Some(v as f64))?),
+        }
+    }
+
+    fn deserialize_char<V>(self, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_str(visitor)
+    }
+
+    fn deserialize_str<V>(self, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self.first()? {
+            Value::String(v) => visitor.visit_borrowed_str(v),
+            Value::Bool(v) => visitor.visit_string(v.to_string()),
+            Value::Integer(v) => visitor.visit_string(v.to_string()),
+            Value::Float(v) => visitor.visit_string(v.to_string()),
+            _ => Err(Error(ParseError::InvalidValue)),
+        }
+    }
+
+    fn deserialize_string<V>(self, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_str(visitor)
+    }
+
+    fn deserialize_bytes<V>(self, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self.first()? {
+            Value::String(v) => visitor.visit_borrowed_bytes(v.as_bytes()),
+            _ => Err(Error(ParseError::InvalidValue)),
+        }
+    }
+
+    fn deserialize_byte_buf<V>(self, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_bytes(visitor)
+    }
+
+    fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        let missing = match self.value {
+            Value::Array(v) => v.is_empty(),
+            Value::Table(t) => t.is_empty(),
+            _ => false,
+        };
+        if missing {
+            visitor.visit_none()
+        } else {
+            visitor.visit_some(self)
+        }
+    }
+
+    fn deserialize_unit<V>(self, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_unit_struct<V>(self, _name: &'static str, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_unit(visitor)
+    }
+
+    fn deserialize_newtype_struct<V>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_seq<V>(self, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        let entries = self.entries()?;
+        visitor.visit_seq(SeqAccess {
+            entries: entries.into_iter().enumerate(),
+            format: self.format,
+        })
+    }
+
+    fn deserialize_tuple<V>(self, _len: usize, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_tuple_struct<V>(
+        self,
+        _name: &'static str,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_map<V>(self, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self.value {
+            Value::Table(t) => visitor.visit_map(MapAccess {
+                entries: t.iter().filter(|(k, _)| !k.is_empty()),
+                current: None,
+                format: self.format,
+            }),
+            v if v.is_empty() => visitor.visit_map(MapAccess {
+                entries: std::iter::empty(),
+                current: None,
+                format: self.format,
+            }),
+            _ => Err(Error(ParseError::InvalidValue)),
+        }
+    }
+
+    fn deserialize_struct<V>(
+        self,
+        _name: &'static str,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_map(visitor)
+    }
+
+    fn deserialize_enum<V>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self.value.as_table() {
+            Some(t) if t.len() == 1 => {
+                let (variant, value) = t.iter().next().expect("The Table has one Entry");
+                visitor.visit_enum(EnumAccess {
+                    variant,
+                    value,
+                    format: self.format,
+                })
+            }
+            _ => match self.first()? {
+                Value::String(v) => visitor.visit_enum(v.as_str().into_deserializer()),
+                _ => Err(Error(ParseError::InvalidValue)),
+            },
+        }
+    }
+
+    fn deserialize_identifier<V>(self, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_str(visitor)
+    }
+
+    fn deserialize_ignored_any<V>(self, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_unit()
+    }
+}
+
+struct SeqAccess<I> {
+    entries: I,
+    format: KeyFormat,
+}
+
+impl<'de, I> de::SeqAccess<'de> for SeqAccess<I>
+where
+    I: Iterator<Item = (usize, &'de Value)>,
+{
+    type Error = Error;
+
+    fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>, Error>
+    where
+        T: de::DeserializeSeed<'de>,
+    {
+        match self.entries.next() {
+            Some((index, value)) => seed
+                .deserialize(Deserializer::with_format(value, &self.format))
+                .map(Some)
+                .map_err(|e| e.at(&index.to_string(), &self.format)),
+            None => Ok(None),
+        }
+    }
+}
+
+struct MapAccess<'de, I> {
+    entries: I,
+    current: Option<(&'de String, &'de Value)>,
+    format: KeyFormat,
+}
+
+impl<'de, I> de::MapAccess<'de> for MapAccess<'de, I>
+where
+    I: Iterator<Item = (&'de String, &'de Value)>,
+{
+    type Error = Error;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, Error>
+    where
+        K: de::DeserializeSeed<'de>,
+    {
+        self.current = self.entries.next();
+        match self.current {
+            Some((key, _)) => seed.deserialize(key.as_str().into_deserializer()).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value, Error>
+    where
+        V: de::DeserializeSeed<'de>,
+    {
+        let (key, value) = self
+            .current
+            .take()
+            .expect("`next_value_seed` is only called after `next_key_seed`");
+        seed.deserialize(Deserializer::with_format(value, &self.format))
+            .map_err(|e| e.at(key, &self.format))
+    }
+}
+
+struct EnumAccess<'de> {
+    variant: &'de String,
+    value: &'de Value,
+    format: KeyFormat,
+}
+
+impl<'de> de::EnumAccess<'de> for EnumAccess<'de> {
+    type Error = Error;
+    type Variant = Self;
+
+    fn variant_seed<V>(self, seed: V) -> Result<(V::Value, Self), Error>
+    where
+        V: de::DeserializeSeed<'de>,
+    {
+        let variant = seed.deserialize(self.variant.as_str().into_deserializer())?;
+        Ok((variant, self))
+    }
+}
+
+impl<'de> de::VariantAccess<'de> for EnumAccess<'de> {
+    type Error = Error;
+
+    fn unit_variant(self) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn newtype_variant_seed<T>(self, seed: T) -> Result<T::Value, Error>
+    where
+        T: de::DeserializeSeed<'de>,
+    {
+        seed.deserialize(Deserializer::with_format(self.value, &self.format))
+            .map_err(|e| e.at(self.variant, &self.format))
+    }
+
+    fn tuple_variant<V>(self, _len: usize, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        de::Deserializer::deserialize_seq(
+            Deserializer::with_format(self.value, &self.format),
+            visitor,
+        )
+        .map_err(|e| e.at(self.variant, &self.format))
+    }
+
+    fn struct_variant<V>(
+        self,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        de::Deserializer::deserialize_map(
+            Deserializer::with_format(self.value, &self.format),
+            visitor,
+        )
+        .map_err(|e| e.at(self.variant, &self.format))
+    }
+}
@@ -0,0 +1,25 @@
+use std::collections::HashMap;
+
+/// Defines the Interface used to look up the Environment-Variables that
+/// Fields are bound to using `#[argser(env = "...")]`, which allows for
+/// replacing the actual Environment of the Process, like in Tests
+pub trait Environment {
+    /// Loads the Value of the Variable with the given Name, if it is set
+    fn var(&self, name: &str) -> Option<String>;
+}
+
+/// The Environment of the current Process, which is used by default
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ProcessEnv;
+
+impl Environment for ProcessEnv {
+    fn var(&self, name: &str) -> Option<String> {
+        std::env::var(name).ok()
+    }
+}
+
+impl Environment for HashMap<String, String> {
+    fn var(&self, name: &str) -> Option<String> {
+        self.get(name).cloned()
+    }
+}
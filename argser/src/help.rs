@@ -0,0 +1,74 @@
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+use crate::{ArgumentDetail, FromArgs};
+
+/// Renders the Help-Text for all the Arguments of `T`, with one Line per
+/// Argument
+///
+/// # Example
+/// ```text
+/// -name  [required]
+/// -port  [default: 8080]
+/// ```
+pub fn help<T>() -> String
+where
+    T: FromArgs,
+{
+    render_help(&T::arguments())
+}
+
+/// Renders the Help-Text for the given List of Arguments, with one Line per
+/// Argument and the Details aligned after the longest Name
+pub fn render_help(args: &[ArgumentDetail]) -> String {
+    let width = args.iter().map(|a| a.name.len()).max().unwrap_or(0);
+
+    let mut result = String::new();
+    for arg in args {
+        let line = format!("-{:width$} {}", arg.name, arg, width = width);
+        result.push_str(line.trim_end());
+        result.push('\n');
+    }
+    result
+}
+
+/// Displays the Details of the Argument, like the Description and Default,
+/// but not the Name itself
+impl Display for ArgumentDetail {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let mut parts = Vec::new();
+        if !self.description.is_empty() {
+            parts.push(self.description.clone());
+        }
+        if self.required {
+            parts.push("[required]".to_owned());
+        }
+        if let Some(env) = &self.env {
+            parts.push(format!("[env: {}]", env));
+        }
+        if let Some(default) = &self.default {
+            parts.push(format!("[default: {}]", default));
+        }
+
+        write!(f, "{}", parts.join(" "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render() {
+        let args = vec![
+            ArgumentDetail::new("name").required(true),
+            ArgumentDetail::new("port")
+                .description("The Port to listen on")
+                .default("8080")
+                .env("PORT"),
+            ArgumentDetail::new("db.host"),
+        ];
+
+        let expected = "-name    [required]\n-port    The Port to listen on [env: PORT] [default: 8080]\n-db.host\n";
+        assert_eq!(expected, render_help(&args));
+    }
+}
@@ -0,0 +1,91 @@
+use crate::{KeyFormat, ParseError, Value};
+
+/// The Suffixes that mark an Argument as containing the Path to a File, which
+/// contains the actual Value
+const SUFFIXES: &[&str] = &["_FILE", "_file", ".file"];
+
+/// Loads the Value for the Argument with the given Name from the Tree of
+/// Arguments, while also supporting the `_FILE` Convention used for Secrets.
+///
+/// If the Argument itself is not present, but one of `{name}_FILE`,
+/// `{name}_file` or `{name}.file` is, the Value is instead loaded from the
+/// File at the Path stored in that Argument, with trailing Newlines removed.
+/// If neither is present, an empty Value is returned so that the usual
+/// Default-Handling still applies.
+///
+/// The Name is split into its Segments and normalized according to the given
+/// Format, and `{name}.file` is looked up as the nested `file` Entry, so it
+/// uses the configured Separator as well.
+///
+/// This is used by the Code generated for Fields marked with
+/// `#[argser(file_indirection)]`
+pub fn load_file_indirection_value(
+    node: &Value,
+    name: &str,
+    format: &KeyFormat,
+) -> Result<Value, ParseError> {
+    let value = lookup(node, &format.split(name), format);
+    if !value.is_empty() {
+        return Ok(value);
+    }
+
+    match find_file_path(node, name, format) {
+        Some((file_key, path)) => read_file(file_key, &path).map(Value::String),
+        None => Ok(Value::empty()),
+    }
+}
+
+/// Finds the first of the Arguments `{name}_FILE`, `{name}_file` and
+/// `{name}.file` that is present in the Tree, returning its Name and the
+/// Path to the File stored in it
+pub(crate) fn find_file_path(
+    node: &Value,
+    name: &str,
+    format: &KeyFormat,
+) -> Option<(String, String)> {
+    let mut segments = format.split(name);
+    let last = segments.pop().unwrap_or_default();
+    for suffix in SUFFIXES {
+        let (file_key, file_segments) = match suffix.strip_prefix('.') {
+            Some(nested) => {
+                let mut file_segments = segments.clone();
+                file_segments.push(last.clone());
+                file_segments.push(nested.to_owned());
+                (format.join(name, nested), file_segments)
+            }
+            None => {
+                let mut file_segments = segments.clone();
+                file_segments.push(format!("{}{}", last, suffix));
+                (format!("{}{}", name, suffix), file_segments)
+            }
+        };
+        let path = lookup(node, &file_segments, format)
+            .into_strings()
+            .into_iter()
+            .next();
+        if let Some(path) = path {
+            return Some((file_key, path));
+        }
+    }
+
+    None
+}
+
+fn lookup(node: &Value, segments: &[String], format: &KeyFormat) -> Value {
+    let path: Vec<&str> = segments.iter().map(String::as_str).collect();
+    node.lookup_path(&path, format)
+}
+
+/// Reads the Content of the File at the given Path, which was stored in the
+/// Argument with the given Name, with trailing Newlines removed
+fn read_file(name: String, path: &str) -> Result<String, ParseError> {
+    let mut content = std::fs::read_to_string(path).map_err(|e| ParseError::InvalidFile {
+        name,
+        path: path.to_owned(),
+        message: e.to_string(),
+    })?;
+    let trimmed_len = content.trim_end_matches(&['\n', '\r'][..]).len();
+    content.truncate(trimmed_len);
+
+    Ok(content)
+}
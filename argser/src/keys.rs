@@ -0,0 +1,330 @@
+use std::borrow::Cow;
+
+/// Describes how the Names of Arguments are split into the Segments that
+/// are used to find the Arguments of SubCategories, Maps and Lists.
+///
+/// Segments are separated by the Separator, which defaults to `.`, and a
+/// Segment that should contain the Separator itself can be quoted, like the
+/// Name of the Map-Entry in `backends."api.example.com".port`. In quoted
+/// Segments, `\"` and `\\` can be used to include a Quote or Backslash.
+///
+/// The Format can also normalize the Segments, so that Names which only
+/// differ in their Spelling, like `Max-Conns` and `max_conns`, refer to the
+/// same Argument.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct KeyFormat {
+    separator: char,
+    fold_case: bool,
+    unify_dashes: bool,
+}
+
+impl Default for KeyFormat {
+    fn default() -> Self {
+        Self::new('.')
+    }
+}
+
+impl KeyFormat {
+    /// Creates a new Format with the given Separator
+    pub fn new(separator: char) -> Self {
+        Self {
+            separator,
+            fold_case: false,
+            unify_dashes: false,
+        }
+    }
+
+    /// The Character that separates the Segments of a Name
+    pub fn separator(&self) -> char {
+        self.separator
+    }
+
+    /// Updates the `separator` Option for this Instance
+    pub fn set_separator(&mut self, nvalue: char) {
+        self.separator = nvalue;
+    }
+
+    /// Whether Segments are compared case-insensitively, by turning them
+    /// into lowercase
+    pub fn folds_case(&self) -> bool {
+        self.fold_case
+    }
+
+    /// Updates the `fold_case` Option for this Instance
+    pub fn fold_case(&mut self, nvalue: bool) {
+        self.fold_case = nvalue;
+    }
+
+    /// Whether `-` and `_` are treated as the same Character in Segments, by
+    /// replacing all `-` with `_`
+    pub fn unifies_dashes(&self) -> bool {
+        self.unify_dashes
+    }
+
+    /// Updates the `unify_dashes` Option for this Instance
+    pub fn unify_dashes(&mut self, nvalue: bool) {
+        self.unify_dashes = nvalue;
+    }
+
+    /// Whether any Normalization is applied to the Segments
+    pub(crate) fn normalizes(&self) -> bool {
+        self.fold_case || self.unify_dashes
+    }
+
+    /// Normalizes a single Segment according to the configured Options
+    pub fn normalize<'a>(&self, segment: &'a str) -> Cow<'a, str> {
+        if !self.normalizes() {
+            return Cow::Borrowed(segment);
+        }
+
+        let mut result = String::with_capacity(segment.len());
+        for c in segment.chars() {
+            match c {
+                '-' if self.unify_dashes => result.push('_'),
+                c if self.fold_case => result.extend(c.to_lowercase()),
+                c => result.push(c),
+            };
+        }
+        Cow::Owned(result)
+    }
+
+    /// Whether the given Name consists of only a single Segment, which does
+    /// not need to be split
+    pub(crate) fn is_single(&self, name: &str) -> bool {
+        !name.contains(self.separator) && !name.starts_with('"')
+    }
+
+    /// Splits the given Name into its Segments, while removing the Quotes
+    /// around quoted Segments
+    pub fn split(&self, name: &str) -> Vec<String> {
+        let mut result = Vec::new();
+        let mut segment = String::new();
+        let mut at_start = true;
+
+        let mut chars = name.chars().peekable();
+        while let Some(c) = chars.next() {
+            if at_start && c == '"' {
+                while let Some(c) = chars.next() {
+                    match c {
+                        '"' => break,
+                        '\\' if matches!(chars.peek(), Some('"') | Some('\\')) => {
+                            segment.extend(chars.next());
+                        }
+                        c => segment.push(c),
+                    };
+                }
+                at_start = false;
+            } else if c == self.separator {
+                result.push(std::mem::take(&mut segment));
+                at_start = true;
+            } else {
+                segment.push(c);
+                at_start = false;
+            }
+        }
+        result.push(segment);
+
+        result
+    }
+
+    /// Quotes the given Segment, if it contains the Separator or would
+    /// otherwise be mistaken for a quoted Segment
+    pub fn quote<'a>(&self, segment: &'a str) -> Cow<'a, str> {
+        if self.is_single(segment) {
+            return Cow::Borrowed(segment);
+        }
+
+        let mut result = String::with_capacity(segment.len() + 2);
+        result.push('"');
+        for c in segment.chars() {
+            if c == '"' || c == '\\' {
+                result.push('\\');
+            }
+            result.push(c);
+        }
+        result.push('"');
+        Cow::Owned(result)
+    }
+
+    /// Appends the given Segment to the given Name, while quoting it if
+    /// necessary
+    pub fn join(&self, name: &str, segment: &str) -> String {
+        let segment = self.quote(segment);
+        if name.is_empty() {
+            return segment.into_owned();
+        }
+        format!("{}{}{}", name, self.separator, segment)
+    }
+}
+
+/// Two Entries of the same Table, whose Keys only differ in their Spelling
+/// and were normalized to the same Key
+#[derive(Debug, PartialEq)]
+pub(crate) struct KeyConflict {
+    /// The normalized Segments of the Path to the merged Entry
+    pub path: Vec<String>,
+    /// The full Name of the first Entry, as it was provided
+    pub first: String,
+    /// The full Name of the second Entry, as it was provided
+    pub second: String,
+}
+
+impl KeyConflict {
+    /// Whether the conflicting Entries are used by the Argument with the
+    /// given Name, which may contain the `{name}` and `{index}` Placeholders
+    /// used for Maps and Lists
+    pub fn affects(&self, name: &str, format: &KeyFormat) -> bool {
+        let pattern = format.split(name);
+        let matches = |(segment, pattern): (&String, &String)| {
+            pattern == "{name}" || pattern == "{index}" || format.normalize(pattern) == *segment
+        };
+
+        if self.path.len() <= pattern.len() {
+            self.path.iter().zip(pattern.iter()).all(matches)
+        } else {
+            // The Keys of Maps of Primitives may span multiple Segments
+            pattern.last().map(String::as_str) == Some("{name}")
+                && self.path.iter().zip(pattern.iter()).all(matches)
+        }
+    }
+}
+
+/// The Names of the declared Arguments, split into their Segments, which tell
+/// the Names of Arguments apart from the Keys of Maps while normalizing, as
+/// the Keys of Maps are Data provided by the User and kept as they are
+pub(crate) struct KeyPatterns {
+    patterns: Vec<Vec<String>>,
+}
+
+impl KeyPatterns {
+    /// Splits the given Names of Arguments, which may contain the `{name}`
+    /// and `{index}` Placeholders used for Maps and Lists
+    pub fn new<'a, I>(names: I, format: &KeyFormat) -> Self
+    where
+        I: IntoIterator<Item = &'a str>,
+    {
+        let patterns = names
+            .into_iter()
+            .map(|name| {
+                format
+                    .split(name)
+                    .into_iter()
+                    .map(|segment| match segment.as_str() {
+                        "{name}" | "{index}" => segment,
+                        _ => format.normalize(&segment).into_owned(),
+                    })
+                    .collect()
+            })
+            .collect();
+        Self { patterns }
+    }
+
+    /// Whether the given Key of the Table at the given Path is the Key of a
+    /// Map, where the Path consists of the already normalized Segments
+    pub fn is_map_key(&self, path: &[String], key: &str, format: &KeyFormat) -> bool {
+        let normalized = format.normalize(key);
+        let mut map_key = false;
+        for pattern in self.patterns.iter() {
+            let matches = path
+                .iter()
+                .zip(pattern.iter())
+                .all(|(segment, pattern)| is_placeholder(pattern) || pattern == segment);
+            if !matches {
+                continue;
+            }
+
+            match pattern.get(path.len()) {
+                // The Name of an Argument takes Precedence over a Map
+                Some(segment) if *segment == normalized => return false,
+                Some(segment) if segment == "{name}" => map_key = true,
+                Some(_) => {}
+                // The Keys of Maps of Primitives may span multiple Segments
+                None => map_key |= pattern.last().map(String::as_str) == Some("{name}"),
+            };
+        }
+        map_key
+    }
+}
+
+fn is_placeholder(segment: &str) -> bool {
+    segment == "{name}" || segment == "{index}"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The Macro has its own Copy of these Rules, which is checked against
+    // the same Cases in `tests/separator.rs`
+    #[test]
+    fn split() {
+        let format = KeyFormat::default();
+        assert_eq!(vec!["port"], format.split("port"));
+        assert_eq!(vec!["db", "host"], format.split("db.host"));
+        assert_eq!(
+            vec!["backends", "api.example.com", "port"],
+            format.split("backends.\"api.example.com\".port")
+        );
+        assert_eq!(vec!["say", "\"hi\"\\"], format.split(r#"say."\"hi\"\\""#));
+        assert_eq!(vec!["a", ""], format.split("a."));
+
+        let format = KeyFormat::new('/');
+        assert_eq!(
+            vec!["backends", "api.example.com", "port"],
+            format.split("backends/api.example.com/port")
+        );
+    }
+
+    #[test]
+    fn normalize() {
+        let mut format = KeyFormat::default();
+        assert_eq!("Max-Conns", format.normalize("Max-Conns"));
+
+        format.fold_case(true);
+        assert_eq!("max-conns", format.normalize("Max-Conns"));
+
+        format.unify_dashes(true);
+        assert_eq!("max_conns", format.normalize("Max-Conns"));
+
+        format.fold_case(false);
+        assert_eq!("Max_Conns", format.normalize("Max-Conns"));
+    }
+
+    #[test]
+    fn join() {
+        let format = KeyFormat::default();
+        assert_eq!("db.host", format.join("db", "host"));
+        assert_eq!("host", format.join("", "host"));
+        assert_eq!(
+            "backends.\"api.example.com\"",
+            format.join("backends", "api.example.com")
+        );
+
+        let name = format.join("say", "\"hi\"\\");
+        assert_eq!(vec!["say", "\"hi\"\\"], format.split(&name));
+    }
+
+    #[test]
+    fn map_keys() {
+        let mut format = KeyFormat::default();
+        format.fold_case(true);
+        let patterns = KeyPatterns::new(
+            vec![
+                "labels.{name}",
+                "backends.{name}.Port",
+                "servers.{index}.host",
+            ],
+            &format,
+        );
+
+        let path =
+            |segments: &[&str]| -> Vec<String> { segments.iter().map(|s| s.to_string()).collect() };
+        assert!(!patterns.is_map_key(&[], "Labels", &format));
+        assert!(patterns.is_map_key(&path(&["labels"]), "App", &format));
+        assert!(patterns.is_map_key(&path(&["labels", "api"]), "Example", &format));
+        assert!(patterns.is_map_key(&path(&["backends"]), "Api", &format));
+        assert!(!patterns.is_map_key(&path(&["backends", "Api"]), "PORT", &format));
+        assert!(!patterns.is_map_key(&path(&["servers", "0"]), "Host", &format));
+        assert!(!patterns.is_map_key(&path(&["unknown"]), "Key", &format));
+    }
+}
@@ -1,17 +1,50 @@
 #![warn(missing_docs)]
 #![doc = include_str!("../README.md")]
 
-use std::collections::HashMap;
+use std::collections::HashSet;
 
-pub use argser_macros::argser;
+pub use argser_macros::{argser, FromArgs};
 
 pub mod provider;
 
 mod traits;
 pub use traits::*;
 
+mod indirection;
+pub use indirection::load_file_indirection_value;
+
+mod context;
+pub use context::{ParseContext, Warning};
+
+mod environment;
+pub use environment::{Environment, ProcessEnv};
+
+mod help;
+pub use help::{help, render_help};
+
+mod value;
+pub use value::Value;
+
+mod keys;
+pub use keys::KeyFormat;
+use keys::KeyPatterns;
+
+mod rename;
+pub use rename::RenameRule;
+
+mod schema;
+pub use schema::{Argument, Schema, ValueType};
+
+#[cfg(feature = "serde")]
+pub mod de;
+#[cfg(feature = "serde")]
+pub use de::{from_providers, from_providers_with_format};
+
 /// The Error returned when attempting to Parse the Arguments
+///
+/// More Variants may be added in the Future, so matches on it need a wildcard Arm
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ParseError {
     /// Some Paramether was missing
     MissingParam {
@@ -25,6 +58,120 @@ pub enum ParseError {
     InvalidValue,
     /// Some Custom-Error returned by custom implementations for [`ParseFromArgs`]
     Custom(String),
+    /// The Value for some Parameter should have been loaded from a File, but
+    /// that File could not be read
+    InvalidFile {
+        /// The Name of the Argument that contained the Path
+        name: String,
+        /// The Path of the File
+        path: String,
+        /// The Description of the underlying IO-Error
+        message: String,
+    },
+    /// A List is missing the Entry with some Index, while Entries with higher
+    /// Indices are present
+    MissingIndex {
+        /// The Name of the missing Entry, like `{name}.{index}`
+        name: String,
+    },
+    /// The Index used for an Entry of a List is not a valid Index
+    InvalidIndex {
+        /// The Name of the invalid Entry
+        name: String,
+    },
+    /// Some Argument was not valid UTF-8
+    InvalidUtf8 {
+        /// The Argument, with all the invalid Parts replaced
+        value: String,
+    },
+    /// The Default-Value declared for some Argument could not be parsed
+    InvalidDefault {
+        /// The full Name of the Argument
+        name: String,
+        /// The Error returned while parsing the Default-Value
+        error: Box<ParseError>,
+    },
+    /// Multiple Fields use the same Name for their Argument, which can happen
+    /// when flattening SubCategories into their parent
+    DuplicateArgument {
+        /// The Name of the Argument
+        name: String,
+    },
+    /// Multiple provided Arguments only differ in their Spelling, like `PORT`
+    /// and `port`, and are normalized to the Name of the same Argument by
+    /// the configured [`KeyFormat`]
+    AmbiguousArgument {
+        /// The normalized Name of the Argument
+        name: String,
+        /// The Name of the first Argument, as it was provided
+        first: String,
+        /// The Name of the second Argument, as it was provided
+        second: String,
+    },
+    /// The Value supplied for some Argument could not be parsed
+    InvalidArgument {
+        /// The full Name of the Argument
+        name: String,
+        /// The Error returned while parsing the Value
+        error: Box<ParseError>,
+    },
+}
+
+impl ParseError {
+    /// Prepends the given Prefix to the Names of all the Arguments referenced
+    /// by this Error, which is used to report the full Name of Arguments in
+    /// SubCategories, Maps and Lists
+    pub fn with_prefix(self, prefix: &str) -> Self {
+        self.with_prefix_separated(prefix, '.')
+    }
+
+    /// The same as [`ParseError::with_prefix`], but joins the Prefix and the
+    /// Names using the given Separator
+    pub fn with_prefix_separated(self, prefix: &str, separator: char) -> Self {
+        let prefixed = |name: String| format!("{}{}{}", prefix, separator, name);
+
+        match self {
+            Self::MissingParam { name } => Self::MissingParam {
+                name: prefixed(name),
+            },
+            Self::InvalidFile {
+                name,
+                path,
+                message,
+            } => Self::InvalidFile {
+                name: prefixed(name),
+                path,
+                message,
+            },
+            Self::MissingIndex { name } => Self::MissingIndex {
+                name: prefixed(name),
+            },
+            Self::InvalidIndex { name } => Self::InvalidIndex {
+                name: prefixed(name),
+            },
+            Self::DuplicateArgument { name } => Self::DuplicateArgument {
+                name: prefixed(name),
+            },
+            Self::AmbiguousArgument {
+                name,
+                first,
+                second,
+            } => Self::AmbiguousArgument {
+                name: prefixed(name),
+                first: prefixed(first),
+                second: prefixed(second),
+            },
+            Self::InvalidDefault { name, error } => Self::InvalidDefault {
+                name: prefixed(name),
+                error,
+            },
+            Self::InvalidArgument { name, error } => Self::InvalidArgument {
+                name: prefixed(name),
+                error,
+            },
+            other => other,
+        }
+    }
 }
 
 /// Defines the interface that needs to be implemented by Argument-Providers,
@@ -33,16 +180,43 @@ pub enum ParseError {
 pub trait ArgProvider {
     /// Get the list of Argument-Pairs from the given Argument-Provider
     fn get_args(&self) -> Vec<(String, String)>;
+
+    /// Get the list of Argument-Pairs from the given Argument-Provider, while
+    /// reporting Problems with the Source of the Arguments as Errors instead
+    /// of working around them.
+    ///
+    /// This defaults to [`ArgProvider::get_args`] and is what is used by
+    /// [`parse_args_from_providers`]
+    fn try_get_args(&self) -> Result<Vec<(String, String)>, ParseError> {
+        Ok(self.get_args())
+    }
+
+    /// Get the Tree of Arguments from the given Argument-Provider, which
+    /// allows Providers for structured Formats to keep typed Values and
+    /// nested Arrays, instead of turning them into Strings.
+    ///
+    /// This defaults to building the Tree from [`ArgProvider::try_get_args`],
+    /// by splitting the Names according to the given Format, and is what is
+    /// used by [`parse_args_from_providers`]
+    fn try_get_value(&self, format: &KeyFormat) -> Result<Value, ParseError> {
+        Ok(Value::from_pairs_with(self.try_get_args()?, format))
+    }
 }
 
-// TODO
-// Support command structures like -test.{name}.test
-// struct Cli {
-//   test: HashMap<String, SubCategory>,
-// }
-// struct SubCategory {
-//   test: String,
-// }
+/// Checks that no two of the given Arguments share the same Name, which is
+/// used by Structs with flattened SubCategories in
+/// [`FromArgs::check_arguments`]
+pub fn check_collisions(args: &[ArgumentDetail]) -> Result<(), ParseError> {
+    let mut seen = HashSet::new();
+    for arg in args {
+        if !seen.insert(arg.name.as_str()) {
+            return Err(ParseError::DuplicateArgument {
+                name: arg.name.clone(),
+            });
+        }
+    }
+    Ok(())
+}
 
 /// This will load all the Arguments from the given Providers and then attempt
 /// to parse an instance of `T` from that Collection of Arguments
@@ -50,34 +224,137 @@ pub fn parse_args_from_providers<T>(providers: &[&dyn ArgProvider]) -> Result<T,
 where
     T: FromArgs,
 {
-    let all_args: HashMap<String, Vec<String>> = {
-        let mut tmp: HashMap<String, Vec<String>> = HashMap::new();
-        for inner_vec in providers.iter().map(|p| p.get_args()) {
-            for (key, value) in inner_vec {
-                match tmp.get_mut(&key) {
-                    Some(previous) => {
-                        previous.push(value);
-                    }
-                    None => {
-                        tmp.insert(key, vec![value]);
-                    }
-                };
-            }
-        }
-        tmp
+    parse_args_from_providers_with_context(providers, &mut ParseContext::new())
+}
+
+/// The same as [`parse_args_from_providers`], but uses the given Context
+/// while parsing, which can afterwards be used to inspect the
+/// [`Warning`]s that were encountered
+pub fn parse_args_from_providers_with_context<T>(
+    providers: &[&dyn ArgProvider],
+    ctx: &mut ParseContext,
+) -> Result<T, ParseError>
+where
+    T: FromArgs,
+{
+    T::check_arguments()?;
+
+    let format = ctx.key_format().unwrap_or_else(T::key_format);
+    let arguments = if format.normalizes() {
+        T::arguments()
+    } else {
+        Vec::new()
     };
+    let all_values = collect_values(providers, &format, Some(&arguments))?;
+
+    T::from_value(&all_values, ctx)
+}
+
+/// This will load all the Arguments from the given Providers and then parse
+/// them according to the given [`Schema`], while splitting and normalizing
+/// their Names using the [`KeyFormat`] of the Schema
+pub fn parse_schema_from_providers(
+    schema: &Schema,
+    providers: &[&dyn ArgProvider],
+) -> Result<Value, ParseError> {
+    let format = &schema.key_format;
+    let arguments = if format.normalizes() {
+        schema.arguments()
+    } else {
+        Vec::new()
+    };
+    let all_values = collect_values(providers, format, Some(&arguments))?;
+
+    schema.parse_value(&all_values)
+}
+
+/// Loads the Trees of Arguments from the given Providers and merges them
+/// into a single Tree, where the Values for the same Argument are collected
+/// in the Order of the Providers.
+///
+/// If the Format normalizes the Keys, the Tree of every Provider is
+/// normalized on its own, see [`normalize_keys`], so the same Argument can
+/// still be overridden by another Provider using a different Spelling. If
+/// the Arguments are not known up front, every Collision is ambiguous.
+fn collect_values(
+    providers: &[&dyn ArgProvider],
+    format: &KeyFormat,
+    arguments: Option<&[ArgumentDetail]>,
+) -> Result<Value, ParseError> {
+    let mut result = Value::table();
+    for provider in providers.iter() {
+        let value = provider.try_get_value(format)?;
+        if format.normalizes() {
+            result.merge(check_normalized(value, format, arguments)?);
+        } else {
+            result.merge(value);
+        }
+    }
+    Ok(result)
+}
+
+/// Normalizes the Keys of the given Tree of Arguments according to the given
+/// Format, if it normalizes Keys at all, which is used by the generated Code.
+///
+/// Only the Segments that correspond to the Names of the given Arguments are
+/// normalized, while the Keys of Maps, which stand in for `{name}`, are kept
+/// as they were provided.
+///
+/// Returns [`ParseError::AmbiguousArgument`] if two of the Keys are
+/// normalized to the Name of one of the given Arguments, while Collisions of
+/// Keys that are not used by any Argument are ignored
+pub fn normalize_keys<F>(
+    value: Value,
+    format: &KeyFormat,
+    arguments: F,
+) -> Result<Value, ParseError>
+where
+    F: FnOnce() -> Vec<ArgumentDetail>,
+{
+    if !format.normalizes() {
+        return Ok(value);
+    }
+    check_normalized(value, format, Some(&arguments()))
+}
+
+fn check_normalized(
+    value: Value,
+    format: &KeyFormat,
+    arguments: Option<&[ArgumentDetail]>,
+) -> Result<Value, ParseError> {
+    let patterns = arguments
+        .map(|arguments| KeyPatterns::new(arguments.iter().map(|a| a.name.as_str()), format));
+    let mut conflicts = Vec::new();
+    let value = value.normalize_keys(format, patterns.as_ref(), &mut conflicts);
 
-    T::parse(all_args)
+    let conflict = conflicts.into_iter().find(|c| match arguments {
+        Some(arguments) => arguments.iter().any(|a| c.affects(&a.name, format)),
+        None => true,
+    });
+    match conflict {
+        Some(c) => Err(ParseError::AmbiguousArgument {
+            name: c
+                .path
+                .iter()
+                .fold(String::new(), |name, s| format.join(&name, s)),
+            first: c.first,
+            second: c.second,
+        }),
+        None => Ok(value),
+    }
 }
 
 /// This is a simple Wrapper for [`parse_args_from_providers`] that
 /// automatically uses the [`Cli`](provider::Cli) and [`Env`](provider::Env)
-/// ArgProvider to collect Arguments and then Parse them
+/// ArgProvider to collect Arguments and then Parse them, where the
+/// Positional-Arguments are only collected for Tuple-Structs, see
+/// [`FromArgs::positional`]
 pub fn parse_cli<T>() -> Result<T, ParseError>
 where
     T: FromArgs,
 {
-    let cli = provider::Cli::default();
+    let mut cli = provider::Cli::default();
+    cli.collect_positional(T::positional());
     let env = provider::Env::default();
 
     parse_args_from_providers(&[&cli, &env])
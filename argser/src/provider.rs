@@ -7,5 +7,14 @@ pub use cli::Cli;
 mod envs;
 pub use envs::Env;
 
+mod dotenv;
+pub use dotenv::{DotEnv, DotEnvError};
+
+mod directory;
+pub use directory::Directory;
+
+mod prompt;
+pub use prompt::{Prompt, PromptError};
+
 mod fixed;
 pub use fixed::*;
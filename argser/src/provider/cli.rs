@@ -1,4 +1,8 @@
-use crate::ArgProvider;
+use std::{ffi::OsString, iter::FromIterator};
+
+use crate::{ArgProvider, ParseError};
+
+mod response_file;
 
 /// This ArgProvider collects all the CLI-Arguments passed to the Program
 ///
@@ -6,27 +10,117 @@ use crate::ArgProvider;
 /// This Provider accepts all the CLI-Flags that are in one of these Formats:
 /// * "-{name} {value}"
 /// * "-{name}={value}"
-pub struct Cli {}
+///
+/// # Response-Files
+/// When enabled using [`Cli::expand_response_files`], every Argument in the
+/// Form `@{path}` is replaced by the Arguments contained in the File at that
+/// Path, which are split up like a Shell would do it. Response-Files can
+/// themselves reference other Response-Files up to a limited Depth.
+/// References to Files that can not be read, or that are nested too deeply,
+/// are reported as [`ParseError::InvalidFile`] by
+/// [`try_get_args`](ArgProvider::try_get_args), while
+/// [`get_args`](ArgProvider::get_args) keeps them as they are.
+///
+/// # Sources
+/// By default the Arguments passed to the Program are used, but the Provider
+/// can also be constructed from any other List of Arguments, using
+/// [`Cli::from_os_args`] for a List that starts with the Program-Name like
+/// [`std::env::args_os`] or [`FromIterator`] for a List of only the
+/// Arguments themselves.
+///
+/// # Positional Arguments
+/// When enabled using [`Cli::collect_positional`], every Argument that is
+/// neither a Flag nor the Value of a Flag is passed on using its Position
+/// among those Arguments as its Name, so `-v 1 first second` results in the
+/// Arguments `0=first` and `1=second`. These are used by Tuple-Structs.
+///
+/// # Non-UTF-8
+/// Arguments that are not valid UTF-8 cause
+/// [`try_get_args`](ArgProvider::try_get_args) to fail with
+/// [`ParseError::InvalidUtf8`], while [`get_args`](ArgProvider::get_args)
+/// replaces the invalid Parts instead.
+pub struct Cli {
+    args: Option<Vec<OsString>>,
+    skip_program_name: bool,
+    response_files: bool,
+    positional: bool,
+}
 
 impl Cli {
     /// Creates a new Instance of the ArgProvider
     pub fn new() -> Self {
-        Self {}
+        Self {
+            args: None,
+            skip_program_name: true,
+            response_files: false,
+            positional: false,
+        }
+    }
+
+    /// Creates a new Instance of the ArgProvider, which uses the given
+    /// Arguments instead of the ones passed to the Program.
+    ///
+    /// Just like [`std::env::args_os`], the first Item is expected to be the
+    /// Name of the Program and is therefore skipped
+    pub fn from_os_args<I, S>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<OsString>,
+    {
+        Self {
+            args: Some(iter.into_iter().map(|a| a.into()).collect()),
+            skip_program_name: true,
+            response_files: false,
+            positional: false,
+        }
+    }
+
+    /// Whether or not the first Argument is skipped, because it contains the
+    /// Name of the Program
+    pub fn skips_program_name(&self) -> bool {
+        self.skip_program_name
+    }
+
+    /// Updates the `skip_program_name` Option for this Instance
+    pub fn skip_program_name(&mut self, nvalue: bool) {
+        self.skip_program_name = nvalue;
     }
 
-    fn parse_vars<I>(mut iter: I) -> Vec<(String, String)>
+    /// Whether or not `@{path}` Arguments are expanded to the Content of the
+    /// referenced Response-File
+    pub fn expands_response_files(&self) -> bool {
+        self.response_files
+    }
+
+    /// Updates the `response_files` Option for this Instance
+    pub fn expand_response_files(&mut self, nvalue: bool) {
+        self.response_files = nvalue;
+    }
+
+    /// Whether or not Positional-Arguments are passed on, using their
+    /// Position as their Name
+    pub fn collects_positional(&self) -> bool {
+        self.positional
+    }
+
+    /// Updates the `positional` Option for this Instance
+    pub fn collect_positional(&mut self, nvalue: bool) {
+        self.positional = nvalue;
+    }
+
+    fn parse_vars<I>(mut iter: I, positional: bool) -> Vec<(String, String)>
     where
         I: Iterator<Item = String>,
     {
         let mut result = Vec::new();
+        let mut position = 0;
 
-        loop {
-            let item = match iter.next() {
-                Some(i) => i,
-                None => break,
-            };
-
+        while let Some(item) = iter.next() {
             if !item.starts_with('-') {
+                if positional {
+                    result.push((position.to_string(), item));
+                    position += 1;
+                }
                 continue;
             }
 
@@ -53,12 +147,77 @@ impl Cli {
 
         result
     }
+
+    fn raw_args(&self) -> Vec<OsString> {
+        let raw = match &self.args {
+            Some(args) => args.clone(),
+            None => std::env::args_os().collect(),
+        };
+
+        let skip = if self.skip_program_name { 1 } else { 0 };
+        raw.into_iter().skip(skip).collect()
+    }
+
+    fn parse_strings(&self, vars: Vec<String>) -> Vec<(String, String)> {
+        if self.response_files {
+            Self::parse_vars(
+                response_file::expand(vars.into_iter()).into_iter(),
+                self.positional,
+            )
+        } else {
+            Self::parse_vars(vars.into_iter(), self.positional)
+        }
+    }
+
+    /// The same as [`Cli::parse_strings`], but reports Response-Files that
+    /// could not be expanded as Errors
+    fn try_parse_strings(&self, vars: Vec<String>) -> Result<Vec<(String, String)>, ParseError> {
+        let vars = if self.response_files {
+            response_file::try_expand(vars.into_iter())?
+        } else {
+            vars
+        };
+        Ok(Self::parse_vars(vars.into_iter(), self.positional))
+    }
 }
 impl ArgProvider for Cli {
     fn get_args(&self) -> Vec<(String, String)> {
-        let vars = std::env::args();
+        let vars = self
+            .raw_args()
+            .into_iter()
+            .map(|raw| raw.to_string_lossy().into_owned())
+            .collect();
+
+        self.parse_strings(vars)
+    }
+
+    fn try_get_args(&self) -> Result<Vec<(String, String)>, ParseError> {
+        let vars = self
+            .raw_args()
+            .into_iter()
+            .map(|raw| {
+                raw.into_string().map_err(|raw| ParseError::InvalidUtf8 {
+                    value: raw.to_string_lossy().into_owned(),
+                })
+            })
+            .collect::<Result<_, _>>()?;
 
-        Self::parse_vars(vars)
+        self.try_parse_strings(vars)
+    }
+}
+/// Creates the ArgProvider from a List of Arguments, that does not contain
+/// the Name of the Program
+impl<S> FromIterator<S> for Cli
+where
+    S: Into<OsString>,
+{
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+    {
+        let mut result = Self::from_os_args(iter);
+        result.skip_program_name(false);
+        result
     }
 }
 impl Default for Cli {
@@ -75,7 +234,7 @@ mod tests {
     fn parse_vars_1_pair() {
         let input = vec!["-p".to_owned(), "8080".to_owned()];
 
-        let result = Cli::parse_vars(input.into_iter());
+        let result = Cli::parse_vars(input.into_iter(), false);
         let expected = vec![("p".to_owned(), "8080".to_owned())];
 
         assert_eq!(expected, result);
@@ -85,9 +244,89 @@ mod tests {
     fn parse_vars_single_pair() {
         let input = vec!["-p=8080".to_owned()];
 
-        let result = Cli::parse_vars(input.into_iter());
+        let result = Cli::parse_vars(input.into_iter(), false);
         let expected = vec![("p".to_owned(), "8080".to_owned())];
 
         assert_eq!(expected, result);
     }
+
+    #[test]
+    fn from_iter() {
+        let cli = Cli::from_iter(vec!["-p", "8080", "-name=test"]);
+
+        let expected = vec![
+            ("p".to_owned(), "8080".to_owned()),
+            ("name".to_owned(), "test".to_owned()),
+        ];
+
+        assert_eq!(Ok(expected.clone()), cli.try_get_args());
+        assert_eq!(expected, cli.get_args());
+    }
+
+    #[test]
+    fn from_os_args_skips_program_name() {
+        let mut cli = Cli::from_os_args(vec!["-program", "-p", "8080"]);
+        assert_eq!(vec![("p".to_owned(), "8080".to_owned())], cli.get_args());
+
+        cli.skip_program_name(false);
+        assert_eq!(
+            vec![("program".to_owned(), "-p".to_owned())],
+            cli.get_args()
+        );
+    }
+
+    #[test]
+    fn positional() {
+        let mut cli = Cli::from_iter(vec!["first", "-p", "8080", "second"]);
+        assert_eq!(vec![("p".to_owned(), "8080".to_owned())], cli.get_args());
+
+        cli.collect_positional(true);
+        let expected = vec![
+            ("0".to_owned(), "first".to_owned()),
+            ("p".to_owned(), "8080".to_owned()),
+            ("1".to_owned(), "second".to_owned()),
+        ];
+        assert_eq!(expected, cli.get_args());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn non_utf8() {
+        use std::os::unix::ffi::OsStringExt;
+
+        let invalid = OsString::from_vec(vec![b't', b'e', 0xff, b's', b't']);
+        let cli = Cli::from_iter(vec![OsString::from("-name"), invalid]);
+
+        assert_eq!(
+            Err(ParseError::InvalidUtf8 {
+                value: "te\u{FFFD}st".to_owned()
+            }),
+            cli.try_get_args()
+        );
+        assert_eq!(
+            vec![("name".to_owned(), "te\u{FFFD}st".to_owned())],
+            cli.get_args()
+        );
+    }
+
+    #[test]
+    fn missing_response_file() {
+        let mut cli = Cli::from_iter(vec!["-name", "test", "@/argser/does/not/exist"]);
+        cli.collect_positional(true);
+        cli.expand_response_files(true);
+
+        match cli.try_get_args() {
+            Err(ParseError::InvalidFile { name, .. }) => {
+                assert_eq!("@/argser/does/not/exist", name)
+            }
+            other => panic!("Expected an InvalidFile-Error but got {:?}", other),
+        };
+        assert_eq!(
+            vec![
+                ("name".to_owned(), "test".to_owned()),
+                ("0".to_owned(), "@/argser/does/not/exist".to_owned())
+            ],
+            cli.get_args()
+        );
+    }
 }
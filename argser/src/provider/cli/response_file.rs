@@ -0,0 +1,281 @@
+//! Handles the Expansion of Response-Files, which are referenced on the
+//! Command-Line using `@{path}` and contain further Arguments
+
+use crate::ParseError;
+
+/// The maximum Depth up to which Response-Files referenced in other
+/// Response-Files are still expanded
+const MAX_DEPTH: usize = 8;
+
+/// Replaces every `@{path}` Argument with the Arguments contained in the File
+/// at that Path.
+///
+/// Arguments referencing Files that can not be read, or that are nested
+/// deeper than [`MAX_DEPTH`], are kept as they are
+pub(super) fn expand<I>(args: I) -> Vec<String>
+where
+    I: Iterator<Item = String>,
+{
+    let mut result = Vec::new();
+    for arg in args {
+        // Expanding leniently never returns an Error
+        let _ = expand_arg(arg, 0, false, &mut result);
+    }
+    result
+}
+
+/// The same as [`expand`], but returns [`ParseError::InvalidFile`] for
+/// Response-Files that can not be read or that are nested too deeply
+pub(super) fn try_expand<I>(args: I) -> Result<Vec<String>, ParseError>
+where
+    I: Iterator<Item = String>,
+{
+    let mut result = Vec::new();
+    for arg in args {
+        expand_arg(arg, 0, true, &mut result)?;
+    }
+    Ok(result)
+}
+
+fn expand_arg(
+    arg: String,
+    depth: usize,
+    strict: bool,
+    result: &mut Vec<String>,
+) -> Result<(), ParseError> {
+    let path = match arg.strip_prefix('@') {
+        Some(p) if !p.is_empty() => p,
+        _ => {
+            result.push(arg);
+            return Ok(());
+        }
+    };
+
+    let invalid_file = |message: String| ParseError::InvalidFile {
+        name: arg.clone(),
+        path: path.to_owned(),
+        message,
+    };
+
+    if depth >= MAX_DEPTH {
+        if strict {
+            return Err(invalid_file(format!(
+                "Response-Files can only be nested {} Levels deep",
+                MAX_DEPTH
+            )));
+        }
+        result.push(arg);
+        return Ok(());
+    }
+
+    let content = match std::fs::read_to_string(path) {
+        Ok(c) => c,
+        Err(e) if strict => return Err(invalid_file(e.to_string())),
+        Err(_) => {
+            result.push(arg);
+            return Ok(());
+        }
+    };
+
+    for token in tokenize(&content) {
+        expand_arg(token, depth + 1, strict, result)?;
+    }
+    Ok(())
+}
+
+/// Splits the Content of a Response-File into the single Arguments, using
+/// the same Rules as a Shell would.
+///
+/// * Arguments are separated by Whitespace
+/// * `#` at the start of an Argument starts a Comment until the end of the Line
+/// * Single Quotes keep everything in them as is
+/// * Double Quotes keep everything in them, except for the Escapes `\"`,
+///   `\\`, `\$` and `` \` ``
+/// * Outside of Quotes a Backslash escapes the next Character and a Backslash
+///   at the end of a Line continues the Argument on the next Line
+pub(super) fn tokenize(content: &str) -> Vec<String> {
+    let mut result = Vec::new();
+    let mut current = String::new();
+    let mut in_token = false;
+
+    let mut chars = content.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            c if c.is_whitespace() => {
+                if in_token {
+                    result.push(std::mem::take(&mut current));
+                    in_token = false;
+                }
+            }
+            '#' if !in_token => {
+                for c in chars.by_ref() {
+                    if c == '\n' {
+                        break;
+                    }
+                }
+            }
+            '\'' => {
+                in_token = true;
+                for c in chars.by_ref() {
+                    if c == '\'' {
+                        break;
+                    }
+                    current.push(c);
+                }
+            }
+            '"' => {
+                in_token = true;
+                while let Some(c) = chars.next() {
+                    match c {
+                        '"' => break,
+                        '\\' => match chars.next() {
+                            Some(c @ '"') | Some(c @ '\\') | Some(c @ '$') | Some(c @ '`') => {
+                                current.push(c)
+                            }
+                            Some('\n') => {}
+                            Some(c) => {
+                                current.push('\\');
+                                current.push(c);
+                            }
+                            None => current.push('\\'),
+                        },
+                        c => current.push(c),
+                    };
+                }
+            }
+            '\\' => match chars.next() {
+                Some('\n') => {}
+                Some(c) => {
+                    in_token = true;
+                    current.push(c);
+                }
+                None => {
+                    in_token = true;
+                    current.push('\\');
+                }
+            },
+            c => {
+                in_token = true;
+                current.push(c);
+            }
+        };
+    }
+
+    if in_token {
+        result.push(current);
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strings(values: &[&str]) -> Vec<String> {
+        values.iter().map(|v| v.to_string()).collect()
+    }
+
+    fn response_file(name: &str, content: &str) -> String {
+        let path = std::env::temp_dir().join(format!(
+            "argser-response-file-{}-{}",
+            name,
+            std::process::id()
+        ));
+        std::fs::write(&path, content).unwrap();
+        path.to_str().unwrap().to_owned()
+    }
+
+    #[test]
+    fn tokenize_simple() {
+        assert_eq!(
+            strings(&["-p", "8080", "-name=test"]),
+            tokenize("-p 8080\n  -name=test\n")
+        );
+    }
+
+    #[test]
+    fn tokenize_quotes_and_escapes() {
+        let content =
+            r#"-name 'single $quoted' -other "double \"quoted\"" split\ word "" mixed"quo"'ted'"#;
+
+        assert_eq!(
+            strings(&[
+                "-name",
+                "single $quoted",
+                "-other",
+                "double \"quoted\"",
+                "split word",
+                "",
+                "mixedquoted"
+            ]),
+            tokenize(content)
+        );
+    }
+
+    #[test]
+    fn tokenize_comments() {
+        let content = "# the port\n-p 8080 # inline\n-name a#b\n";
+
+        assert_eq!(strings(&["-p", "8080", "-name", "a#b"]), tokenize(content));
+    }
+
+    #[test]
+    fn expand_nested() {
+        let inner = response_file("inner", "-port 8080\n");
+        let outer = response_file("outer", &format!("-name test @{}\n", inner));
+
+        assert_eq!(
+            strings(&["program", "-name", "test", "-port", "8080", "-other", "1"]),
+            expand(strings(&["program", &format!("@{}", outer), "-other", "1"]).into_iter())
+        );
+
+        std::fs::remove_file(inner).unwrap();
+        std::fs::remove_file(outer).unwrap();
+    }
+
+    #[test]
+    fn expand_missing_and_recursive() {
+        let recursive_path = std::env::temp_dir().join(format!(
+            "argser-response-file-recursive-{}",
+            std::process::id()
+        ));
+        let recursive_path = recursive_path.to_str().unwrap().to_owned();
+        std::fs::write(&recursive_path, format!("-a 1 @{}", recursive_path)).unwrap();
+
+        let result = expand(
+            strings(&["@/argser/does/not/exist", &format!("@{}", recursive_path)]).into_iter(),
+        );
+
+        let mut expected = strings(&["@/argser/does/not/exist"]);
+        for _ in 0..MAX_DEPTH {
+            expected.push("-a".to_owned());
+            expected.push("1".to_owned());
+        }
+        expected.push(format!("@{}", recursive_path));
+        assert_eq!(expected, result);
+
+        std::fs::remove_file(recursive_path).unwrap();
+    }
+
+    #[test]
+    fn try_expand_missing_and_recursive() {
+        match try_expand(strings(&["-a", "@/argser/does/not/exist"]).into_iter()) {
+            Err(ParseError::InvalidFile { name, path, .. }) => {
+                assert_eq!("@/argser/does/not/exist", name);
+                assert_eq!("/argser/does/not/exist", path);
+            }
+            other => panic!("Expected an InvalidFile-Error but got {:?}", other),
+        };
+
+        let recursive_path = response_file("strict-recursive", "");
+        std::fs::write(&recursive_path, format!("-a 1 @{}", recursive_path)).unwrap();
+
+        match try_expand(strings(&[&format!("@{}", recursive_path)]).into_iter()) {
+            Err(ParseError::InvalidFile { path, .. }) => assert_eq!(recursive_path, path),
+            other => panic!("Expected an InvalidFile-Error but got {:?}", other),
+        };
+
+        std::fs::remove_file(recursive_path).unwrap();
+    }
+}
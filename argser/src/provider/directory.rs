@@ -0,0 +1,221 @@
+use std::path::{Path, PathBuf};
+
+use crate::{ArgProvider, KeyFormat, ParseError, Value};
+
+/// The maximum Depth up to which nested Directories are still considered,
+/// which protects against Symlink-Loops
+const MAX_DEPTH: usize = 16;
+
+/// The ArgProvider for a Directory, in which every File is a single
+/// Argument.
+///
+/// This is the Layout used for mounted Secrets and ConfigMaps in Kubernetes,
+/// where the relative Path of a File is the Name of the Argument and its
+/// Content is the Value, so the File `con/port` is turned into the Argument
+/// `con.port`. When loaded as a Tree using
+/// [`try_get_value`](ArgProvider::try_get_value), the Directories are joined
+/// using the Separator of the given [`KeyFormat`] instead.
+///
+/// Files and Directories whose Names start with a `.` are ignored, which
+/// includes the `..data` Symlinks and timestamped Directories created by
+/// Kubernetes. Files that can not be read or do not contain valid UTF-8 are
+/// skipped as well.
+pub struct Directory {
+    path: PathBuf,
+    trim_newline: bool,
+}
+
+impl Directory {
+    /// Creates a new Instance of the ArgProvider for the Directory at the
+    /// given Path, which trims trailing Newlines from the Values by default
+    pub fn new<P>(path: P) -> Self
+    where
+        P: Into<PathBuf>,
+    {
+        Self {
+            path: path.into(),
+            trim_newline: true,
+        }
+    }
+
+    /// The Path of the Directory that is loaded
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Whether or not trailing Newlines are removed from the Values
+    pub fn trims_trailing_newline(&self) -> bool {
+        self.trim_newline
+    }
+
+    /// Updates the `trim_newline` Option for this Instance
+    pub fn trim_trailing_newline(&mut self, nvalue: bool) {
+        self.trim_newline = nvalue;
+    }
+
+    fn collect(
+        &self,
+        dir: &Path,
+        prefix: &str,
+        separator: char,
+        depth: usize,
+        result: &mut Vec<(String, String)>,
+    ) {
+        if depth > MAX_DEPTH {
+            return;
+        }
+
+        let entries = match std::fs::read_dir(dir) {
+            Ok(e) => e,
+            Err(_) => return,
+        };
+
+        let mut entries: Vec<_> = entries.filter_map(|e| e.ok()).collect();
+        entries.sort_by_key(|e| e.file_name());
+
+        for entry in entries {
+            let name = match entry.file_name().into_string() {
+                Ok(n) => n,
+                Err(_) => continue,
+            };
+            if name.starts_with('.') {
+                continue;
+            }
+
+            let key = format!("{}{}", prefix, name);
+            let path = entry.path();
+
+            // This follows Symlinks, as every Entry in a mounted Secret is a
+            // Symlink into the `..data` Directory
+            let metadata = match std::fs::metadata(&path) {
+                Ok(m) => m,
+                Err(_) => continue,
+            };
+
+            if metadata.is_dir() {
+                let prefix = format!("{}{}", key, separator);
+                self.collect(&path, &prefix, separator, depth + 1, result);
+                continue;
+            }
+
+            let mut value = match std::fs::read_to_string(&path) {
+                Ok(v) => v,
+                Err(_) => continue,
+            };
+            if self.trim_newline {
+                let trimmed_len = value.trim_end_matches(&['\n', '\r'][..]).len();
+                value.truncate(trimmed_len);
+            }
+
+            result.push((key, value));
+        }
+    }
+}
+
+impl ArgProvider for Directory {
+    fn get_args(&self) -> Vec<(String, String)> {
+        let mut result = Vec::new();
+        self.collect(&self.path, "", '.', 0, &mut result);
+        result
+    }
+
+    fn try_get_value(&self, format: &KeyFormat) -> Result<Value, ParseError> {
+        let mut result = Vec::new();
+        self.collect(&self.path, "", format.separator(), 0, &mut result);
+        Ok(Value::from_pairs_with(result, format))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_dir(name: &str) -> PathBuf {
+        let path =
+            std::env::temp_dir().join(format!("argser-directory-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&path);
+        std::fs::create_dir_all(&path).unwrap();
+        path
+    }
+
+    fn pair(key: &str, value: &str) -> (String, String) {
+        (key.to_owned(), value.to_owned())
+    }
+
+    #[test]
+    fn nested_files() {
+        let dir = test_dir("nested");
+        std::fs::write(dir.join("name"), "test-name\n").unwrap();
+        std::fs::create_dir(dir.join("con")).unwrap();
+        std::fs::write(dir.join("con").join("port"), "123").unwrap();
+        std::fs::write(dir.join(".hidden"), "hidden").unwrap();
+
+        let provider = Directory::new(&dir);
+        assert_eq!(
+            vec![pair("con.port", "123"), pair("name", "test-name")],
+            provider.get_args()
+        );
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn custom_separator() {
+        let dir = test_dir("separator");
+        std::fs::create_dir(dir.join("con")).unwrap();
+        std::fs::write(dir.join("con").join("port"), "123").unwrap();
+        std::fs::write(dir.join("log.level"), "debug").unwrap();
+
+        let provider = Directory::new(&dir);
+        let value = provider.try_get_value(&KeyFormat::new('/')).unwrap();
+        assert_eq!(
+            Some("123"),
+            value
+                .get("con")
+                .and_then(|v| v.get("port"))
+                .and_then(Value::as_str)
+        );
+        assert_eq!(
+            Some("debug"),
+            value.get("log.level").and_then(Value::as_str)
+        );
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn without_trimming() {
+        let dir = test_dir("untrimmed");
+        std::fs::write(dir.join("name"), "test-name\n").unwrap();
+
+        let mut provider = Directory::new(&dir);
+        provider.trim_trailing_newline(false);
+        assert_eq!(vec![pair("name", "test-name\n")], provider.get_args());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn kubernetes_layout() {
+        use std::os::unix::fs::symlink;
+
+        let dir = test_dir("kubernetes");
+        let timestamped = dir.join("..2021_01_01_00_00_00.000000000");
+        std::fs::create_dir_all(timestamped.join("con")).unwrap();
+        std::fs::write(timestamped.join("password"), "secret\n").unwrap();
+        std::fs::write(timestamped.join("con").join("port"), "123\n").unwrap();
+
+        symlink(&timestamped, dir.join("..data")).unwrap();
+        symlink("..data/password", dir.join("password")).unwrap();
+        symlink("..data/con", dir.join("con")).unwrap();
+
+        let provider = Directory::new(&dir);
+        assert_eq!(
+            vec![pair("con.port", "123"), pair("password", "secret")],
+            provider.get_args()
+        );
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,454 @@
+use std::{fmt, iter::Peekable, path::Path, str::Chars, str::FromStr};
+
+use super::envs::transform_key;
+use crate::ArgProvider;
+
+/// The Error returned when a `.env`-File could not be loaded
+#[derive(Debug)]
+pub enum DotEnvError {
+    /// The File could not be read
+    Io(std::io::Error),
+    /// The Content of the File is not valid `.env`-Syntax
+    Syntax {
+        /// The Line (starting at 1) on which the Error was found
+        line: usize,
+        /// A Description of what is wrong
+        message: String,
+    },
+}
+
+impl fmt::Display for DotEnvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "Could not read .env-File: {}", e),
+            Self::Syntax { line, message } => write!(f, "Line {}: {}", line, message),
+        }
+    }
+}
+
+impl std::error::Error for DotEnvError {}
+
+impl From<std::io::Error> for DotEnvError {
+    fn from(e: std::io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+/// The ArgProvider for Variables stored in a `.env`-File
+///
+/// The File is only parsed and the Variables are never added to the actual
+/// Environment of the Process, but the Prefix and Lowercase handling is the
+/// same as for the [`Env`](crate::provider::Env) Provider.
+///
+/// # Syntax
+/// * Empty Lines and Lines starting with `#` are ignored
+/// * Every other Line is in the Format `{name}={value}` and may start with
+///   `export `
+/// * Unquoted Values end at the end of the Line or at a ` #` Comment
+/// * Values in single Quotes are taken literally
+/// * Values in double Quotes support the Escapes `\n`, `\r`, `\t`, `\\`,
+///   `\"` and `\$`
+/// * Quoted Values can span multiple Lines
+/// * `${VAR}` and `$VAR` in unquoted and double quoted Values are replaced
+///   with Variables defined earlier in the File or the Environment
+pub struct DotEnv {
+    vars: Vec<(String, String)>,
+    to_lowercase: bool,
+    prefix: Option<String>,
+}
+
+impl DotEnv {
+    /// Loads and parses the `.env`-File at the given Path
+    pub fn from_path<P>(path: P) -> Result<Self, DotEnvError>
+    where
+        P: AsRef<Path>,
+    {
+        let content = std::fs::read_to_string(path)?;
+        content.parse()
+    }
+
+    /// Whether or not the Names of the Variables are converted to lowercase
+    /// before being passed on
+    pub fn converts_to_lowercase(&self) -> bool {
+        self.to_lowercase
+    }
+
+    /// Updates the `to_lowercase` Option for this Instance
+    pub fn convert_to_lowercase(&mut self, nvalue: bool) {
+        self.to_lowercase = nvalue;
+    }
+
+    /// The Prefix that Variables need to have in order to be passed on, if
+    /// one is configured
+    pub fn prefix(&self) -> Option<&str> {
+        self.prefix.as_deref()
+    }
+
+    /// Updates the `prefix` Option for this Instance, see
+    /// [`Env::set_prefix`](crate::provider::Env::set_prefix)
+    pub fn set_prefix(&mut self, nvalue: Option<String>) {
+        self.prefix = nvalue;
+    }
+}
+
+impl FromStr for DotEnv {
+    type Err = DotEnvError;
+
+    fn from_str(content: &str) -> Result<Self, Self::Err> {
+        let vars = Parser::new(content).parse()?;
+
+        Ok(Self {
+            vars,
+            to_lowercase: false,
+            prefix: None,
+        })
+    }
+}
+
+impl ArgProvider for DotEnv {
+    fn get_args(&self) -> Vec<(String, String)> {
+        self.vars
+            .iter()
+            .filter_map(|(key, value)| {
+                let key = transform_key(key, self.prefix(), self.to_lowercase)?;
+                Some((key, value.clone()))
+            })
+            .collect()
+    }
+}
+
+struct Parser<'a> {
+    chars: Peekable<Chars<'a>>,
+    line: usize,
+    vars: Vec<(String, String)>,
+}
+
+impl<'a> Parser<'a> {
+    fn new(content: &'a str) -> Self {
+        Self {
+            chars: content.chars().peekable(),
+            line: 1,
+            vars: Vec::new(),
+        }
+    }
+
+    fn error<T, M>(&self, message: M) -> Result<T, DotEnvError>
+    where
+        M: Into<String>,
+    {
+        Err(DotEnvError::Syntax {
+            line: self.line,
+            message: message.into(),
+        })
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let c = self.chars.next()?;
+        if c == '\n' {
+            self.line += 1;
+        }
+        Some(c)
+    }
+
+    fn skip_blanks(&mut self) {
+        while let Some(' ') | Some('\t') = self.chars.peek() {
+            self.next();
+        }
+    }
+
+    fn skip_line(&mut self) {
+        while let Some(c) = self.next() {
+            if c == '\n' {
+                break;
+            }
+        }
+    }
+
+    fn parse(mut self) -> Result<Vec<(String, String)>, DotEnvError> {
+        loop {
+            match self.chars.peek() {
+                None => break,
+                Some(c) if c.is_whitespace() => {
+                    self.next();
+                }
+                Some('#') => self.skip_line(),
+                Some(_) => {
+                    let (key, value) = self.parse_pair()?;
+                    self.vars.push((key, value));
+                }
+            };
+        }
+
+        Ok(self.vars)
+    }
+
+    fn parse_key(&mut self) -> String {
+        let mut key = String::new();
+        while let Some(&c) = self.chars.peek() {
+            if c == '=' || c.is_whitespace() {
+                break;
+            }
+            key.push(c);
+            self.next();
+        }
+        key
+    }
+
+    fn parse_pair(&mut self) -> Result<(String, String), DotEnvError> {
+        let mut key = self.parse_key();
+        if key == "export" && matches!(self.chars.peek(), Some(' ') | Some('\t')) {
+            self.skip_blanks();
+            key = self.parse_key();
+        }
+
+        if key.is_empty() {
+            return self.error("Missing the Name of the Variable");
+        }
+        if !key
+            .chars()
+            .all(|c| c.is_alphanumeric() || matches!(c, '_' | '.' | '-'))
+        {
+            return self.error(format!("Invalid Variable-Name '{}'", key));
+        }
+
+        self.skip_blanks();
+        if self.chars.peek() != Some(&'=') {
+            return self.error(format!("Expected '=' after '{}'", key));
+        }
+        self.next();
+        self.skip_blanks();
+
+        let value = match self.chars.peek() {
+            Some('\'') => {
+                self.next();
+                let value = self.parse_single_quoted()?;
+                self.finish_line()?;
+                value
+            }
+            Some('"') => {
+                self.next();
+                let value = self.parse_double_quoted()?;
+                self.finish_line()?;
+                value
+            }
+            _ => self.parse_unquoted()?,
+        };
+
+        Ok((key, value))
+    }
+
+    /// Makes sure that only Whitespace or a Comment follows a quoted Value
+    fn finish_line(&mut self) -> Result<(), DotEnvError> {
+        self.skip_blanks();
+        match self.chars.peek() {
+            None | Some('\n') | Some('\r') => Ok(()),
+            Some('#') => {
+                self.skip_line();
+                Ok(())
+            }
+            Some(c) => {
+                let c = *c;
+                self.error(format!("Unexpected '{}' after the quoted Value", c))
+            }
+        }
+    }
+
+    fn parse_single_quoted(&mut self) -> Result<String, DotEnvError> {
+        let start = self.line;
+        let mut value = String::new();
+        loop {
+            match self.next() {
+                Some('\'') => return Ok(value),
+                Some(c) => value.push(c),
+                None => {
+                    return Err(DotEnvError::Syntax {
+                        line: start,
+                        message: "Unterminated single quoted Value".to_owned(),
+                    })
+                }
+            };
+        }
+    }
+
+    fn parse_double_quoted(&mut self) -> Result<String, DotEnvError> {
+        let start = self.line;
+        let mut value = String::new();
+        loop {
+            match self.next() {
+                Some('"') => return Ok(value),
+                Some('\\') => match self.next() {
+                    Some('n') => value.push('\n'),
+                    Some('r') => value.push('\r'),
+                    Some('t') => value.push('\t'),
+                    Some(c @ '\\') | Some(c @ '"') | Some(c @ '$') => value.push(c),
+                    Some(c) => {
+                        value.push('\\');
+                        value.push(c);
+                    }
+                    None => break,
+                },
+                Some('$') => self.expand(&mut value)?,
+                Some(c) => value.push(c),
+                None => break,
+            };
+        }
+
+        Err(DotEnvError::Syntax {
+            line: start,
+            message: "Unterminated double quoted Value".to_owned(),
+        })
+    }
+
+    fn parse_unquoted(&mut self) -> Result<String, DotEnvError> {
+        let mut value = String::new();
+        while let Some(&c) = self.chars.peek() {
+            match c {
+                '\n' => break,
+                '#' if value.is_empty() || value.ends_with(|c: char| c.is_whitespace()) => {
+                    self.skip_line();
+                    break;
+                }
+                '$' => {
+                    self.next();
+                    self.expand(&mut value)?;
+                }
+                _ => {
+                    value.push(c);
+                    self.next();
+                }
+            };
+        }
+
+        Ok(value.trim_end().to_owned())
+    }
+
+    /// Expands a Reference to another Variable, after the leading `$` has
+    /// already been consumed, and appends the result to the Value
+    fn expand(&mut self, value: &mut String) -> Result<(), DotEnvError> {
+        let braced = self.chars.peek() == Some(&'{');
+        if braced {
+            self.next();
+        }
+
+        let mut name = String::new();
+        while let Some(&c) = self.chars.peek() {
+            if !(c.is_alphanumeric() || c == '_') {
+                break;
+            }
+            name.push(c);
+            self.next();
+        }
+
+        if braced && self.next() != Some('}') {
+            return self.error("Unterminated '${' Reference");
+        }
+        if name.is_empty() {
+            if braced {
+                return self.error("Empty '${}' Reference");
+            }
+            value.push('$');
+            return Ok(());
+        }
+
+        let resolved = self
+            .vars
+            .iter()
+            .rev()
+            .find(|(key, _)| key == &name)
+            .map(|(_, value)| value.clone())
+            .or_else(|| std::env::var(&name).ok());
+        if let Some(resolved) = resolved {
+            value.push_str(&resolved);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(content: &str) -> Vec<(String, String)> {
+        content.parse::<DotEnv>().unwrap().get_args()
+    }
+
+    fn pair(key: &str, value: &str) -> (String, String) {
+        (key.to_owned(), value.to_owned())
+    }
+
+    #[test]
+    fn simple_pairs() {
+        let content = "# A Comment\nPORT=8080\n\nexport NAME = test-name # inline\n";
+
+        assert_eq!(
+            vec![pair("PORT", "8080"), pair("NAME", "test-name")],
+            parse(content)
+        );
+    }
+
+    #[test]
+    fn quoted_values() {
+        let content = "SINGLE='raw \\n $PORT'\nDOUBLE=\"line\\n\\\"quoted\\\" # not a comment\"\n";
+
+        assert_eq!(
+            vec![
+                pair("SINGLE", "raw \\n $PORT"),
+                pair("DOUBLE", "line\n\"quoted\" # not a comment")
+            ],
+            parse(content)
+        );
+    }
+
+    #[test]
+    fn multiline_values() {
+        let content = "KEY=\"first\nsecond\"\nOTHER='a\nb'\n";
+
+        assert_eq!(
+            vec![pair("KEY", "first\nsecond"), pair("OTHER", "a\nb")],
+            parse(content)
+        );
+    }
+
+    #[test]
+    fn expansion() {
+        let content = "HOST=example.com\nURL=http://${HOST}:$ARGSER_DOTENV_UNDEFINED/\nESCAPED=\"\\${HOST}\"\nPORT=1\n";
+
+        assert_eq!(
+            vec![
+                pair("HOST", "example.com"),
+                pair("URL", "http://example.com:/"),
+                pair("ESCAPED", "${HOST}"),
+                pair("PORT", "1"),
+            ],
+            parse(content)
+        );
+    }
+
+    #[test]
+    fn prefix_and_lowercase() {
+        let mut dotenv: DotEnv = "APP_PORT=8080\nOTHER=1\n".parse().unwrap();
+        dotenv.convert_to_lowercase(true);
+        dotenv.set_prefix(Some("APP_".to_owned()));
+
+        assert_eq!(vec![pair("port", "8080")], dotenv.get_args());
+    }
+
+    #[test]
+    fn syntax_errors() {
+        let missing_equals = "VALID=1\nINVALID\n".parse::<DotEnv>();
+        assert!(matches!(
+            missing_equals,
+            Err(DotEnvError::Syntax { line: 2, .. })
+        ));
+
+        let unterminated = "KEY=\"value\n".parse::<DotEnv>();
+        assert!(matches!(
+            unterminated,
+            Err(DotEnvError::Syntax { line: 1, .. })
+        ));
+
+        let trailing = "KEY='value' other\n".parse::<DotEnv>();
+        assert!(matches!(trailing, Err(DotEnvError::Syntax { line: 1, .. })));
+    }
+}
@@ -3,12 +3,36 @@ use crate::ArgProvider;
 /// The ArgProvider for collecting and using Environment-Variables
 pub struct Env {
     to_lowercase: bool,
+    prefix: Option<String>,
+}
+
+/// Applies the Prefix and Lowercase handling, shared by all the Providers that
+/// deal with Environment-Variables, to the Name of a single Variable.
+///
+/// Returns None if the Variable should not be passed on at all
+pub(crate) fn transform_key(key: &str, prefix: Option<&str>, to_lowercase: bool) -> Option<String> {
+    let key = match prefix {
+        Some(prefix) => key.strip_prefix(prefix)?,
+        None => key,
+    };
+    if key.is_empty() {
+        return None;
+    }
+
+    if to_lowercase {
+        Some(key.to_lowercase())
+    } else {
+        Some(key.to_owned())
+    }
 }
 
 impl Env {
     /// Creates a new Instance of the ArgProvider with the given Configuration
     pub fn new(to_lowercase: bool) -> Self {
-        Self { to_lowercase }
+        Self {
+            to_lowercase,
+            prefix: None,
+        }
     }
 
     /// Whether or not the Names of the Environment-Variables are converted to
@@ -22,16 +46,30 @@ impl Env {
         self.to_lowercase = nvalue;
     }
 
+    /// The Prefix that Environment-Variables need to have in order to be
+    /// passed on, if one is configured
+    pub fn prefix(&self) -> Option<&str> {
+        self.prefix.as_deref()
+    }
+
+    /// Updates the `prefix` Option for this Instance.
+    ///
+    /// When a Prefix is set, only the Variables starting with it are passed on
+    /// and the Prefix is stripped from their Names, so `APP_PORT` with the
+    /// Prefix `APP_` becomes `PORT`
+    pub fn set_prefix(&mut self, nvalue: Option<String>) {
+        self.prefix = nvalue;
+    }
+
     fn parse<I>(&self, iter: I) -> Vec<(String, String)>
     where
         I: Iterator<Item = (String, String)>,
     {
-        if self.to_lowercase {
-            iter.map(|(key, value)| (key.to_lowercase(), value))
-                .collect()
-        } else {
-            iter.collect()
-        }
+        iter.filter_map(|(key, value)| {
+            let key = transform_key(&key, self.prefix(), self.to_lowercase)?;
+            Some((key, value))
+        })
+        .collect()
     }
 }
 
@@ -88,4 +126,21 @@ mod tests {
 
         assert_eq!(expected, result);
     }
+
+    #[test]
+    fn parse_with_prefix() {
+        let input = vec![
+            ("APP_PORT".to_owned(), "8080".to_owned()),
+            ("OTHER_PORT".to_owned(), "9090".to_owned()),
+            ("APP_".to_owned(), "empty".to_owned()),
+        ];
+
+        let expected = vec![("port".to_owned(), "8080".to_owned())];
+
+        let mut env = Env::new(true);
+        env.set_prefix(Some("APP_".to_owned()));
+        let result = env.parse(input.into_iter());
+
+        assert_eq!(expected, result);
+    }
 }
@@ -0,0 +1,396 @@
+use std::{
+    fmt,
+    io::{self, BufRead, Stdout, Write},
+};
+
+use crate::{ArgProvider, ArgumentDetail, FromArgs, ParseContext, ParseError, Value};
+
+/// The Error returned when prompting for missing Arguments failed
+#[derive(Debug)]
+pub enum PromptError {
+    /// Reading the Input or writing the Prompt failed, which includes the
+    /// Input ending before all the Arguments were entered
+    Io(io::Error),
+    /// Loading the Arguments from one of the other Providers failed
+    Parse(ParseError),
+}
+
+impl fmt::Display for PromptError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "Could not prompt for Arguments: {}", e),
+            Self::Parse(e) => write!(f, "Could not load Arguments: {:?}", e),
+        }
+    }
+}
+
+impl std::error::Error for PromptError {}
+
+impl From<io::Error> for PromptError {
+    fn from(e: io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+impl From<ParseError> for PromptError {
+    fn from(e: ParseError) -> Self {
+        Self::Parse(e)
+    }
+}
+
+/// The ArgProvider that interactively asks for the required Arguments that
+/// were not supplied by any of the other Providers.
+///
+/// The Prompt has to be filled using [`Prompt::ask_missing`], before it is
+/// passed to [`parse_args_from_providers`](crate::parse_args_from_providers)
+/// together with the other Providers. Every entered Value is validated by
+/// parsing it as the Type of its Field and the Prompt is repeated until a
+/// valid Value was entered.
+///
+/// Arguments marked with `#[argser(secret)]` are entered without showing
+/// them, if the Prompt is configured to hide the Echo of the Terminal, which
+/// is the Default for [`Prompt::stdio`].
+pub struct Prompt<R, W> {
+    input: R,
+    output: W,
+    hide_echo: bool,
+    /// Enables or disables the Echo of the Terminal, which is only replaced
+    /// in the Tests
+    set_echo: fn(bool) -> io::Result<()>,
+    args: Vec<(String, String)>,
+}
+
+impl Prompt<io::StdinLock<'static>, Stdout> {
+    /// Creates a new Prompt that asks on the Terminal, while hiding the
+    /// Input for Secrets
+    pub fn stdio() -> Self {
+        let mut result = Self::new(io::stdin().lock(), io::stdout());
+        result.hide_echo(true);
+        result
+    }
+}
+
+impl<R, W> Prompt<R, W>
+where
+    R: BufRead,
+    W: Write,
+{
+    /// Creates a new Prompt that reads the entered Values from the given
+    /// Input and writes the Prompts to the given Output
+    pub fn new(input: R, output: W) -> Self {
+        Self {
+            input,
+            output,
+            hide_echo: false,
+            set_echo,
+            args: Vec::new(),
+        }
+    }
+
+    /// Whether or not the Echo of the Terminal is disabled while entering
+    /// Secrets
+    pub fn hides_echo(&self) -> bool {
+        self.hide_echo
+    }
+
+    /// Updates the `hide_echo` Option for this Instance.
+    ///
+    /// This uses `stty` to disable the Echo of the Terminal connected to the
+    /// Standard-Input. If that is not possible, because there is no such
+    /// Terminal, `stty` failed or on other Platforms than Unix, a Warning is
+    /// shown and the Secret is entered visibly instead
+    pub fn hide_echo(&mut self, nvalue: bool) {
+        self.hide_echo = nvalue;
+    }
+
+    /// The Arguments that were entered so far
+    pub fn args(&self) -> &[(String, String)] {
+        &self.args
+    }
+
+    /// Asks for every required Argument of `T` that is not supplied by any of
+    /// the given Providers, either directly or through a File for Fields
+    /// marked with `#[argser(file_indirection)]`, and is also not bound to a
+    /// set Environment-Variable
+    pub fn ask_missing<T>(&mut self, providers: &[&dyn ArgProvider]) -> Result<(), PromptError>
+    where
+        T: FromArgs,
+    {
+        self.ask_missing_with_context::<T>(providers, &ParseContext::new())
+    }
+
+    /// The same as [`Prompt::ask_missing`], but uses the Environment and
+    /// [`KeyFormat`](crate::KeyFormat) of the given Context, which should be
+    /// the same one that is later used for parsing
+    pub fn ask_missing_with_context<T>(
+        &mut self,
+        providers: &[&dyn ArgProvider],
+        ctx: &ParseContext,
+    ) -> Result<(), PromptError>
+    where
+        T: FromArgs,
+    {
+        let format = ctx.key_format().unwrap_or_else(T::key_format);
+        let arguments = T::arguments();
+        let supplied = crate::collect_values(providers, &format, Some(&arguments))?;
+
+        for argument in arguments {
+            if !argument.required {
+                continue;
+            }
+
+            let segments = format.split(&argument.name);
+            let path: Vec<&str> = segments.iter().map(String::as_str).collect();
+            if !supplied.lookup_path(&path, &format).is_empty() {
+                continue;
+            }
+            if argument.file_indirection
+                && crate::indirection::find_file_path(&supplied, &argument.name, &format).is_some()
+            {
+                continue;
+            }
+            if let Some(env) = &argument.env {
+                if ctx.env_var(env).is_some() {
+                    continue;
+                }
+            }
+
+            let value = self.ask::<T>(&argument)?;
+            self.args.push((argument.name, value));
+        }
+
+        Ok(())
+    }
+
+    /// Asks for the Value of the given Argument, until a valid Value was
+    /// entered
+    fn ask<T>(&mut self, argument: &ArgumentDetail) -> Result<String, PromptError>
+    where
+        T: FromArgs,
+    {
+        loop {
+            let echo = if argument.secret && self.hide_echo {
+                Some(EchoGuard::disable(self.set_echo))
+            } else {
+                None
+            };
+            if let Some(Err(e)) = &echo {
+                writeln!(
+                    self.output,
+                    "Warning: The Input will be visible, as the Echo could not be disabled: {}",
+                    e
+                )?;
+            }
+
+            if argument.description.is_empty() {
+                write!(self.output, "{}: ", argument.name)?;
+            } else {
+                write!(
+                    self.output,
+                    "{} ({}): ",
+                    argument.name, argument.description
+                )?;
+            }
+            self.output.flush()?;
+
+            let line = self.read_line()?;
+            if let Some(Ok(echo)) = echo {
+                drop(echo);
+                // The Newline entered by the User was not echoed either
+                writeln!(self.output)?;
+            }
+
+            if line.is_empty() {
+                writeln!(self.output, "A Value is required")?;
+                continue;
+            }
+
+            match T::validate_argument(&argument.name, &Value::from(line.as_str())) {
+                Some(Err(e)) => {
+                    writeln!(self.output, "Invalid Value: {}", describe(&e))?;
+                }
+                _ => return Ok(line),
+            };
+        }
+    }
+
+    /// Reads a single Line from the Input, without the trailing Newline
+    fn read_line(&mut self) -> Result<String, PromptError> {
+        let mut line = String::new();
+        if self.input.read_line(&mut line)? == 0 {
+            return Err(PromptError::Io(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "The Input ended before all Arguments were entered",
+            )));
+        }
+
+        let trimmed_len = line.trim_end_matches(&['\n', '\r'][..]).len();
+        line.truncate(trimmed_len);
+        Ok(line)
+    }
+}
+
+impl<R, W> ArgProvider for Prompt<R, W> {
+    fn get_args(&self) -> Vec<(String, String)> {
+        self.args.clone()
+    }
+}
+
+/// A short Description of why a Value was rejected, which is shown to the
+/// User before prompting again
+fn describe(error: &ParseError) -> String {
+    match error {
+        ParseError::Custom(message) => message.clone(),
+        ParseError::InvalidArgument { error, .. } => describe(error),
+        _ => "the Value could not be parsed".to_owned(),
+    }
+}
+
+/// Disables the Echo of the Terminal while it exists
+struct EchoGuard {
+    set_echo: fn(bool) -> io::Result<()>,
+}
+
+impl EchoGuard {
+    fn disable(set_echo: fn(bool) -> io::Result<()>) -> io::Result<Self> {
+        set_echo(false)?;
+        Ok(Self { set_echo })
+    }
+}
+
+impl Drop for EchoGuard {
+    fn drop(&mut self) {
+        let _ = (self.set_echo)(true);
+    }
+}
+
+#[cfg(unix)]
+fn set_echo(enabled: bool) -> io::Result<()> {
+    use std::io::IsTerminal;
+
+    if !io::stdin().is_terminal() {
+        return Err(io::Error::other("the Standard-Input is not a Terminal"));
+    }
+
+    let flag = if enabled { "echo" } else { "-echo" };
+    let status = std::process::Command::new("stty").arg(flag).status()?;
+    if !status.success() {
+        return Err(io::Error::other(format!("stty failed with {}", status)));
+    }
+    Ok(())
+}
+
+#[cfg(not(unix))]
+fn set_echo(_enabled: bool) -> io::Result<()> {
+    Err(io::Error::new(
+        io::ErrorKind::Unsupported,
+        "the Echo can only be disabled on Unix",
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ask(input: &str, argument: &ArgumentDetail) -> (Result<String, PromptError>, String) {
+        ask_with_echo(input, argument, None)
+    }
+
+    /// Asks for the Argument while hiding the Echo using the given Function,
+    /// if there is one
+    fn ask_with_echo(
+        input: &str,
+        argument: &ArgumentDetail,
+        set_echo: Option<fn(bool) -> io::Result<()>>,
+    ) -> (Result<String, PromptError>, String) {
+        let mut output = Vec::new();
+        let result = {
+            let mut prompt = Prompt::new(input.as_bytes(), &mut output);
+            if let Some(set_echo) = set_echo {
+                prompt.hide_echo(true);
+                prompt.set_echo = set_echo;
+            }
+            prompt.ask::<NoValidation>(argument)
+        };
+        (result, String::from_utf8(output).unwrap())
+    }
+
+    struct NoValidation;
+
+    impl FromArgs for NoValidation {
+        fn parse(_: std::collections::HashMap<String, Vec<String>>) -> Result<Self, ParseError> {
+            Ok(Self)
+        }
+
+        fn arguments() -> Vec<ArgumentDetail> {
+            Vec::new()
+        }
+    }
+
+    fn argument(description: &str) -> ArgumentDetail {
+        ArgumentDetail::new("name")
+            .required(true)
+            .description(description)
+    }
+
+    #[test]
+    fn prompt_text() {
+        let (result, output) = ask("test\n", &argument(""));
+        assert_eq!("test", result.unwrap());
+        assert_eq!("name: ", output);
+
+        let (result, output) = ask("test\r\n", &argument("The Name"));
+        assert_eq!("test", result.unwrap());
+        assert_eq!("name (The Name): ", output);
+    }
+
+    #[test]
+    fn empty_and_closed_input() {
+        let (result, output) = ask("\ntest\n", &argument(""));
+        assert_eq!("test", result.unwrap());
+        assert_eq!("name: A Value is required\nname: ", output);
+
+        let (result, _) = ask("", &argument(""));
+        match result {
+            Err(PromptError::Io(e)) => assert_eq!(io::ErrorKind::UnexpectedEof, e.kind()),
+            other => panic!("Expected an IO-Error but got {:?}", other),
+        };
+    }
+
+    #[test]
+    fn secret_hidden() {
+        thread_local! {
+            static ECHO: std::cell::RefCell<Vec<bool>> = const { std::cell::RefCell::new(Vec::new()) };
+        }
+        fn record(enabled: bool) -> io::Result<()> {
+            ECHO.with(|calls| calls.borrow_mut().push(enabled));
+            Ok(())
+        }
+
+        let (result, output) = ask_with_echo("hunter2\n", &argument("").secret(true), Some(record));
+        assert_eq!("hunter2", result.unwrap());
+        assert_eq!("name: \n", output);
+        assert_eq!(vec![false, true], ECHO.with(|calls| calls.borrow().clone()));
+
+        // Arguments that are not Secrets are entered visibly
+        let (result, output) = ask_with_echo("test\n", &argument(""), Some(record));
+        assert_eq!("test", result.unwrap());
+        assert_eq!("name: ", output);
+        assert_eq!(vec![false, true], ECHO.with(|calls| calls.borrow().clone()));
+    }
+
+    #[test]
+    fn secret_without_terminal() {
+        fn fail(_: bool) -> io::Result<()> {
+            Err(io::Error::other("no Terminal"))
+        }
+
+        let (result, output) = ask_with_echo("hunter2\n", &argument("").secret(true), Some(fail));
+        assert_eq!("hunter2", result.unwrap());
+        assert_eq!(
+            "Warning: The Input will be visible, as the Echo could not be disabled: no Terminal\n\
+             name: ",
+            output
+        );
+    }
+}
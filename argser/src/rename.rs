@@ -0,0 +1,75 @@
+/// The Naming-Convention that is applied to the Names of the Fields of a
+/// Struct, which is configured using `rename_all` on the Struct.
+///
+/// SubCategories inherit the Naming-Convention of their parent, unless they
+/// configure their own, which is why the generated Code also needs it while
+/// parsing, see [`ParseContext::rename_rule`](crate::ParseContext::rename_rule)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RenameRule {
+    /// `lowercase`
+    Lower,
+    /// `UPPERCASE`
+    Upper,
+    /// `PascalCase`
+    Pascal,
+    /// `camelCase`
+    Camel,
+    /// `snake_case`
+    Snake,
+    /// `SCREAMING_SNAKE_CASE`
+    ScreamingSnake,
+    /// `kebab-case`
+    Kebab,
+    /// `SCREAMING-KEBAB-CASE`
+    ScreamingKebab,
+}
+
+impl RenameRule {
+    /// Applies the Rule to the given Name of a Field, which is expected to be
+    /// in snake_case like all Rust-Identifiers for Fields.
+    ///
+    /// Just like in serde, `lowercase` and `UPPERCASE` only change the Case
+    /// and keep the Underscores
+    pub fn apply(&self, name: &str) -> String {
+        let words = name.split('_').filter(|w| !w.is_empty());
+
+        let capitalize = |word: &str| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        };
+
+        match self {
+            Self::Lower => name.to_lowercase(),
+            Self::Upper => name.to_uppercase(),
+            Self::Pascal => words.map(capitalize).collect(),
+            Self::Camel => words
+                .enumerate()
+                .map(|(i, w)| if i == 0 { w.to_owned() } else { capitalize(w) })
+                .collect(),
+            Self::Snake => words.collect::<Vec<_>>().join("_"),
+            Self::ScreamingSnake => words.collect::<Vec<_>>().join("_").to_uppercase(),
+            Self::Kebab => words.collect::<Vec<_>>().join("-"),
+            Self::ScreamingKebab => words.collect::<Vec<_>>().join("-").to_uppercase(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn apply() {
+        assert_eq!("max_conns", RenameRule::Lower.apply("max_conns"));
+        assert_eq!("MAX_CONNS", RenameRule::Upper.apply("max_conns"));
+        assert_eq!("MaxConns", RenameRule::Pascal.apply("max_conns"));
+        assert_eq!("maxConns", RenameRule::Camel.apply("max_conns"));
+        assert_eq!("max_conns", RenameRule::Snake.apply("max_conns"));
+        assert_eq!("MAX_CONNS", RenameRule::ScreamingSnake.apply("max_conns"));
+        assert_eq!("max-conns", RenameRule::Kebab.apply("max_conns"));
+        assert_eq!("MAX-CONNS", RenameRule::ScreamingKebab.apply("max_conns"));
+    }
+}
@@ -0,0 +1,564 @@
+use std::collections::{BTreeMap, HashMap};
+
+use crate::{ArgumentDetail, KeyFormat, ParseError, ParseFromArgs, Value};
+
+/// The Type of the Values for an [`Argument`] of a [`Schema`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ValueType {
+    /// The Value is kept as a String
+    String,
+    /// The Value is parsed as a Bool
+    Bool,
+    /// The Value is parsed as a signed Integer
+    Integer,
+    /// The Value is parsed as a Floating-Point Number
+    Float,
+}
+
+impl ValueType {
+    /// Parses a single raw Value into a Value of this Type
+    pub fn parse(&self, raw: &str) -> Result<Value, ParseError> {
+        match self {
+            Self::String => Ok(Value::String(raw.to_owned())),
+            Self::Bool => bool::parse(vec![raw.to_owned()]).map(Value::Bool),
+            Self::Integer => raw
+                .parse()
+                .map(Value::Integer)
+                .map_err(|_| ParseError::InvalidValue),
+            Self::Float => raw
+                .parse()
+                .map(Value::Float)
+                .map_err(|_| ParseError::InvalidValue),
+        }
+    }
+
+    /// Parses a single Value into a Value of this Type, while keeping Values
+    /// that already have this Type, like
+    /// [`ParseFromArgs::parse_value`](crate::ParseFromArgs::parse_value)
+    /// does, so typed Values supplied by a Provider are not turned into
+    /// Strings first
+    pub fn parse_value(&self, value: Value) -> Result<Value, ParseError> {
+        match (self, value) {
+            (_, Value::String(raw)) => self.parse(&raw),
+            (Self::Bool, v @ Value::Bool(_)) => Ok(v),
+            (Self::Integer, v @ Value::Integer(_)) => Ok(v),
+            (Self::Float, v @ Value::Float(_)) => Ok(v),
+            (Self::Float, Value::Integer(v)) => Ok(Value::Float(v as f64)),
+            (Self::String, v @ Value::Bool(_))
+            | (Self::String, v @ Value::Integer(_))
+            | (Self::String, v @ Value::Float(_)) => Ok(Value::String(v.into_strings().remove(0))),
+            _ => Err(ParseError::InvalidValue),
+        }
+    }
+}
+
+type Parser = Box<dyn Fn(Value) -> Result<Value, ParseError>>;
+
+/// A single Argument declared in a [`Schema`]
+pub struct Argument {
+    name: String,
+    parser: Parser,
+    multiple: bool,
+    required: bool,
+    default: Option<String>,
+    description: String,
+}
+
+impl Argument {
+    /// Creates a new optional Argument with the given Name, whose Value is
+    /// kept as a String
+    pub fn new<N>(name: N) -> Self
+    where
+        N: Into<String>,
+    {
+        Self {
+            name: name.into(),
+            parser: Box::new(|value| ValueType::String.parse_value(value)),
+            multiple: false,
+            required: false,
+            default: None,
+            description: String::new(),
+        }
+    }
+
+    /// Parses the Values of the Argument as the given Type
+    pub fn value_type(mut self, ty: ValueType) -> Self {
+        self.parser = Box::new(move |value| ty.parse_value(value));
+        self
+    }
+
+    /// Parses the Values of the Argument using the given Function, which is
+    /// passed the String-Form of every Value
+    pub fn parser<F>(mut self, parser: F) -> Self
+    where
+        F: Fn(&str) -> Result<Value, ParseError> + 'static,
+    {
+        self.parser = Box::new(move |value: Value| match value.into_strings().first() {
+            Some(raw) => parser(raw),
+            None => Err(ParseError::InvalidValue),
+        });
+        self
+    }
+
+    /// Whether all the supplied Values should be collected into an Array,
+    /// instead of only using the first one
+    pub fn multiple(mut self, nvalue: bool) -> Self {
+        self.multiple = nvalue;
+        self
+    }
+
+    /// Whether the Argument has to be supplied, if it has no Default
+    pub fn required(mut self, nvalue: bool) -> Self {
+        self.required = nvalue;
+        self
+    }
+
+    /// The textual Form of the Default-Value, which is parsed like any other
+    /// supplied Value
+    pub fn default<D>(mut self, nvalue: D) -> Self
+    where
+        D: Into<String>,
+    {
+        self.default = Some(nvalue.into());
+        self
+    }
+
+    /// The Description of the Argument, which is shown in the Help-Text
+    pub fn description<D>(mut self, nvalue: D) -> Self
+    where
+        D: Into<String>,
+    {
+        self.description = nvalue.into();
+        self
+    }
+
+    /// Parses the given Value, returning None if the Argument was not
+    /// supplied and is also not required
+    fn parse(&self, value: Value) -> Result<Option<Value>, ParseError> {
+        if value.is_empty() {
+            return match &self.default {
+                Some(default) => self
+                    .parse_values(Value::from(default.as_str()))
+                    .map(Some)
+                    .map_err(|e| ParseError::InvalidDefault {
+                        name: self.name.clone(),
+                        error: Box::new(e),
+                    }),
+                None if self.required => Err(ParseError::MissingParam {
+                    name: self.name.clone(),
+                }),
+                None => Ok(None),
+            };
+        }
+
+        self.parse_values(value)
+            .map(Some)
+            .map_err(|e| ParseError::InvalidArgument {
+                name: self.name.clone(),
+                error: Box::new(e),
+            })
+    }
+
+    fn parse_values(&self, value: Value) -> Result<Value, ParseError> {
+        if self.multiple {
+            let entries = match value {
+                Value::Array(entries) => entries,
+                other => vec![other],
+            };
+            let entries = entries
+                .into_iter()
+                .map(|entry| (self.parser)(entry))
+                .collect::<Result<_, _>>()?;
+            return Ok(Value::Array(entries));
+        }
+
+        match value.into_first() {
+            Some(first) => (self.parser)(first),
+            None => Err(ParseError::MissingValue),
+        }
+    }
+
+    fn detail(&self, name: String, required: bool) -> ArgumentDetail {
+        let result = ArgumentDetail::new(name)
+            .required(required && self.required && self.default.is_none())
+            .description(self.description.as_str());
+        match &self.default {
+            Some(default) => result.default(default.as_str()),
+            None => result,
+        }
+    }
+}
+
+enum Entry {
+    Argument(Argument),
+    SubCategory { name: String, schema: Schema },
+    Map { name: String, schema: Schema },
+    ValueMap(Argument),
+}
+
+/// Describes a Collection of Arguments at Runtime, for Cases where the
+/// Arguments are not known at compile-time and the [`argser`](crate::argser)
+/// Macro can therefore not be used.
+///
+/// Parsing using a Schema results in a [`Value::Table`] containing all the
+/// supplied Arguments.
+///
+/// The Names of the Arguments are split according to the [`KeyFormat`] of
+/// the outermost Schema, see [`Schema::key_format`], while the Formats of
+/// nested Schemas are ignored.
+///
+/// # Example
+/// ```rust
+/// use argser::{Argument, Schema, ValueType};
+///
+/// let schema = Schema::new()
+///     .argument(Argument::new("name").required(true))
+///     .argument(Argument::new("port").value_type(ValueType::Integer).default("8080"))
+///     .subcategory("db", Schema::new().argument(Argument::new("host")));
+///
+/// let mut args = std::collections::HashMap::new();
+/// args.insert("name".to_owned(), vec!["test".to_owned()]);
+/// args.insert("db.host".to_owned(), vec!["localhost".to_owned()]);
+///
+/// let value = schema.parse(args).unwrap();
+/// assert_eq!(Some(8080), value.get("port").and_then(|v| v.as_i64()));
+/// assert_eq!(Some("localhost"), value.pointer("db.host").and_then(|v| v.as_str()));
+/// ```
+#[derive(Default)]
+pub struct Schema {
+    entries: Vec<Entry>,
+    pub(crate) key_format: KeyFormat,
+}
+
+impl Schema {
+    /// Creates a new empty Schema
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The Format used to split the Names of the Arguments into the Segments
+    /// for SubCategories and Maps
+    pub fn key_format(mut self, nvalue: KeyFormat) -> Self {
+        self.key_format = nvalue;
+        self
+    }
+
+    /// Adds the given Argument to the Schema
+    pub fn argument(mut self, argument: Argument) -> Self {
+        self.entries.push(Entry::Argument(argument));
+        self
+    }
+
+    /// Adds a SubCategory with the given Name, whose Arguments are described
+    /// by the given Schema
+    pub fn subcategory<N>(mut self, name: N, schema: Schema) -> Self
+    where
+        N: Into<String>,
+    {
+        self.entries.push(Entry::SubCategory {
+            name: name.into(),
+            schema,
+        });
+        self
+    }
+
+    /// Adds a Map with the given Name, where every Entry is a SubCategory
+    /// described by the given Schema, provided using Arguments like
+    /// `{name}.{key}.{argument}`
+    pub fn map<N>(mut self, name: N, schema: Schema) -> Self
+    where
+        N: Into<String>,
+    {
+        self.entries.push(Entry::Map {
+            name: name.into(),
+            schema,
+        });
+        self
+    }
+
+    /// Adds a Map, named like the given Argument, where every Entry is a
+    /// single Value of that Argument, provided using Arguments like
+    /// `{name}.{key}`
+    pub fn value_map(mut self, argument: Argument) -> Self {
+        self.entries.push(Entry::ValueMap(argument));
+        self
+    }
+
+    /// Parses the given Collection of Arguments into a [`Value::Table`]
+    pub fn parse(&self, args: HashMap<String, Vec<String>>) -> Result<Value, ParseError> {
+        self.parse_value(&Value::from_args_with(args, &self.key_format))
+    }
+
+    /// Parses the given Tree of Arguments into a [`Value::Table`], where
+    /// every SubCategory and Map only looks at its own Part of the Tree
+    pub fn parse_value(&self, node: &Value) -> Result<Value, ParseError> {
+        self.parse_node(node, &self.key_format)
+    }
+
+    fn parse_node(&self, node: &Value, format: &KeyFormat) -> Result<Value, ParseError> {
+        let empty = Value::table();
+        let separator = format.separator();
+        let pointer = |name: &str| {
+            let segments = format.split(name);
+            let path: Vec<&str> = segments.iter().map(String::as_str).collect();
+            node.pointer_path(&path, format)
+        };
+        let mut result = BTreeMap::new();
+
+        for entry in self.entries.iter() {
+            match entry {
+                Entry::Argument(argument) => {
+                    let value = Value::own_or_empty(pointer(&argument.name));
+                    if let Some(value) = argument.parse(value)? {
+                        result.insert(argument.name.clone(), value);
+                    }
+                }
+                Entry::SubCategory { name, schema } => {
+                    let sub_category = pointer(name).unwrap_or(&empty);
+                    let value = schema
+                        .parse_node(sub_category, format)
+                        .map_err(|e| e.with_prefix_separated(name, separator))?;
+                    result.insert(name.clone(), value);
+                }
+                Entry::Map { name, schema } => {
+                    let map = pointer(name).and_then(Value::as_table);
+
+                    let mut entries = BTreeMap::new();
+                    for (entry_name, entry_node) in map.into_iter().flatten() {
+                        if entry_name.is_empty() {
+                            continue;
+                        }
+
+                        let prefix = format.join(name, entry_name);
+                        let value = schema
+                            .parse_node(entry_node, format)
+                            .map_err(|e| e.with_prefix_separated(&prefix, separator))?;
+                        entries.insert(entry_name.clone(), value);
+                    }
+                    result.insert(name.clone(), Value::Table(entries));
+                }
+                Entry::ValueMap(argument) => {
+                    let map = pointer(&argument.name).unwrap_or(&empty);
+
+                    let mut entries = BTreeMap::new();
+                    for (key, value) in map.flat_entries(format) {
+                        let value = argument.parse_values(value.clone()).map_err(|e| {
+                            ParseError::InvalidArgument {
+                                name: format!("{}{}{}", argument.name, separator, key),
+                                error: Box::new(e),
+                            }
+                        })?;
+                        entries.insert(key, value);
+                    }
+                    result.insert(argument.name.clone(), Value::Table(entries));
+                }
+            };
+        }
+
+        Ok(Value::Table(result))
+    }
+
+    /// The Details for all the Arguments declared in the Schema, just like
+    /// [`FromArgs::arguments`](crate::FromArgs::arguments)
+    pub fn arguments(&self) -> Vec<ArgumentDetail> {
+        self.collect_arguments("", true, self.key_format.separator())
+    }
+
+    fn collect_arguments(
+        &self,
+        prefix: &str,
+        required: bool,
+        separator: char,
+    ) -> Vec<ArgumentDetail> {
+        let mut args = Vec::new();
+        for entry in self.entries.iter() {
+            match entry {
+                Entry::Argument(argument) => {
+                    args.push(argument.detail(format!("{}{}", prefix, argument.name), required));
+                }
+                Entry::SubCategory { name, schema } => {
+                    let prefix = format!("{}{}{}", prefix, name, separator);
+                    args.extend(schema.collect_arguments(&prefix, required, separator));
+                }
+                Entry::Map { name, schema } => {
+                    let prefix = format!("{}{}{}{{name}}{}", prefix, name, separator, separator);
+                    args.extend(schema.collect_arguments(&prefix, false, separator));
+                }
+                Entry::ValueMap(argument) => {
+                    let name = format!("{}{}{}{{name}}", prefix, argument.name, separator);
+                    args.push(argument.detail(name, false));
+                }
+            };
+        }
+        args
+    }
+
+    /// Renders the Help-Text for all the Arguments declared in the Schema,
+    /// just like [`help`](crate::help)
+    pub fn help(&self) -> String {
+        crate::render_help(&self.arguments())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(pairs: &[(&str, &str)]) -> HashMap<String, Vec<String>> {
+        let mut result: HashMap<String, Vec<String>> = HashMap::new();
+        for (key, value) in pairs {
+            result
+                .entry(key.to_string())
+                .or_default()
+                .push(value.to_string());
+        }
+        result
+    }
+
+    #[test]
+    fn value_types() {
+        assert_eq!(Ok(Value::Bool(true)), ValueType::Bool.parse("true"));
+        assert_eq!(Ok(Value::Integer(-3)), ValueType::Integer.parse("-3"));
+        assert_eq!(Ok(Value::Float(1.5)), ValueType::Float.parse("1.5"));
+        assert_eq!(Err(ParseError::InvalidValue), ValueType::Integer.parse("a"));
+
+        assert_eq!(
+            Ok(Value::Integer(3)),
+            ValueType::Integer.parse_value(Value::Integer(3))
+        );
+        assert_eq!(
+            Ok(Value::Float(3.0)),
+            ValueType::Float.parse_value(Value::Integer(3))
+        );
+        assert_eq!(
+            Ok(Value::from("3")),
+            ValueType::String.parse_value(Value::Integer(3))
+        );
+        assert_eq!(
+            Err(ParseError::InvalidValue),
+            ValueType::Bool.parse_value(Value::Integer(1))
+        );
+    }
+
+    #[test]
+    fn missing_and_invalid() {
+        let schema = Schema::new()
+            .argument(Argument::new("name").required(true))
+            .argument(Argument::new("port").value_type(ValueType::Integer));
+
+        assert_eq!(
+            Err(ParseError::MissingParam {
+                name: "name".to_owned()
+            }),
+            schema.parse(args(&[]))
+        );
+        assert_eq!(
+            Err(ParseError::InvalidArgument {
+                name: "port".to_owned(),
+                error: Box::new(ParseError::InvalidValue),
+            }),
+            schema.parse(args(&[("name", "test"), ("port", "http")]))
+        );
+
+        let mut expected = BTreeMap::new();
+        expected.insert("name".to_owned(), Value::from("test"));
+        assert_eq!(
+            Ok(Value::Table(expected)),
+            schema.parse(args(&[("name", "test")]))
+        );
+    }
+
+    #[test]
+    fn maps_and_multiple() {
+        let schema = Schema::new()
+            .argument(
+                Argument::new("ports")
+                    .value_type(ValueType::Integer)
+                    .multiple(true),
+            )
+            .value_map(Argument::new("limits").value_type(ValueType::Integer))
+            .map(
+                "backends",
+                Schema::new().argument(Argument::new("host").required(true)),
+            );
+
+        let value = schema
+            .parse(args(&[
+                ("ports", "80"),
+                ("ports", "443"),
+                ("limits.cpu", "2"),
+                ("backends.first.host", "example.com"),
+            ]))
+            .unwrap();
+
+        assert_eq!(Some(&Value::from(vec![80i64, 443])), value.get("ports"));
+        assert_eq!(
+            Some(2),
+            value.pointer("limits.cpu").and_then(|v| v.as_i64())
+        );
+        assert_eq!(
+            Some("example.com"),
+            value
+                .pointer("backends.first.host")
+                .and_then(|v| v.as_str())
+        );
+
+        assert_eq!(
+            Err(ParseError::MissingParam {
+                name: "backends.second.host".to_owned()
+            }),
+            schema.parse(args(&[("backends.second.port", "80")]))
+        );
+    }
+
+    #[test]
+    fn custom_separator() {
+        let schema = Schema::new()
+            .key_format(KeyFormat::new('/'))
+            .subcategory("db", Schema::new().argument(Argument::new("host")))
+            .value_map(Argument::new("limits").value_type(ValueType::Integer))
+            .map(
+                "backends",
+                Schema::new().argument(Argument::new("host").required(true)),
+            );
+
+        let names: Vec<_> = schema.arguments().into_iter().map(|a| a.name).collect();
+        assert_eq!(
+            vec!["db/host", "limits/{name}", "backends/{name}/host"],
+            names
+        );
+
+        let value = schema
+            .parse(args(&[
+                ("db/host", "localhost"),
+                ("backends/api.v1/host", "a"),
+            ]))
+            .unwrap();
+        assert_eq!(
+            Some("localhost"),
+            value.pointer("db.host").and_then(|v| v.as_str())
+        );
+        assert_eq!(
+            Some("a"),
+            value
+                .get("backends")
+                .and_then(|v| v.get("api.v1"))
+                .and_then(|v| v.get("host"))
+                .and_then(|v| v.as_str())
+        );
+
+        assert_eq!(
+            Err(ParseError::InvalidArgument {
+                name: "limits/cpu".to_owned(),
+                error: Box::new(ParseError::InvalidValue),
+            }),
+            schema.parse(args(&[("limits/cpu", "many")]))
+        );
+        assert_eq!(
+            Err(ParseError::MissingParam {
+                name: "backends/second/host".to_owned()
+            }),
+            schema.parse(args(&[("backends/second/port", "80")]))
+        );
+    }
+}
@@ -1,9 +1,13 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, convert::TryFrom, ffi::OsString, path::PathBuf};
 
-use crate::ParseError;
+use crate::{KeyFormat, ParseContext, ParseError, RenameRule, Value};
 
 /// Information regarding a single Argument
+///
+/// New Details are created using [`ArgumentDetail::new`] and the Builder-Methods
+/// for the other Fields, as more Fields may be added in the Future
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub struct ArgumentDetail {
     /// The Name of the Argument
     pub name: String,
@@ -12,6 +16,82 @@ pub struct ArgumentDetail {
     pub required: bool,
     /// A Description for the Argument
     pub description: String,
+    /// The textual Form of the Default-Value for the Argument, if it is known
+    pub default: Option<String>,
+    /// The Environment-Variable the Argument is bound to, if any
+    pub env: Option<String>,
+    /// Whether the Value of the Argument is a Secret, which should not be
+    /// displayed, like when it is entered using a
+    /// [`Prompt`](crate::provider::Prompt)
+    pub secret: bool,
+    /// Whether the Value can also be loaded from a File, whose Path is
+    /// supplied using `{name}_FILE`, see [`load_file_indirection_value`](crate::load_file_indirection_value)
+    pub file_indirection: bool,
+}
+
+impl ArgumentDetail {
+    /// Creates the Details for an optional Argument with the given Name, that
+    /// has no Description, Default-Value or Environment-Variable, is not a
+    /// Secret and can not be loaded from a File
+    pub fn new<N>(name: N) -> Self
+    where
+        N: Into<String>,
+    {
+        Self {
+            name: name.into(),
+            required: false,
+            description: String::new(),
+            default: None,
+            env: None,
+            secret: false,
+            file_indirection: false,
+        }
+    }
+
+    /// Updates whether or not the Argument is required
+    pub fn required(mut self, nvalue: bool) -> Self {
+        self.required = nvalue;
+        self
+    }
+
+    /// Updates the Description of the Argument
+    pub fn description<D>(mut self, nvalue: D) -> Self
+    where
+        D: Into<String>,
+    {
+        self.description = nvalue.into();
+        self
+    }
+
+    /// Updates the textual Form of the Default-Value of the Argument
+    pub fn default<D>(mut self, nvalue: D) -> Self
+    where
+        D: Into<String>,
+    {
+        self.default = Some(nvalue.into());
+        self
+    }
+
+    /// Updates the Environment-Variable the Argument is bound to
+    pub fn env<E>(mut self, nvalue: E) -> Self
+    where
+        E: Into<String>,
+    {
+        self.env = Some(nvalue.into());
+        self
+    }
+
+    /// Updates whether or not the Value of the Argument is a Secret
+    pub fn secret(mut self, nvalue: bool) -> Self {
+        self.secret = nvalue;
+        self
+    }
+
+    /// Updates whether or not the Value can also be loaded from a File
+    pub fn file_indirection(mut self, nvalue: bool) -> Self {
+        self.file_indirection = nvalue;
+        self
+    }
 }
 
 /// Defines the Interface to parse a Collection of provided Arguments into a
@@ -26,8 +106,102 @@ where
     /// Parses the given Collection of Arguments into a valid instance of Self
     fn parse(args: HashMap<String, Vec<String>>) -> Result<Self, ParseError>;
 
+    /// Parses the given Collection of Arguments into a valid instance of Self,
+    /// while using the given Context to report [`Warning`](crate::Warning)s
+    ///
+    /// This defaults to [`FromArgs::parse`]
+    fn parse_with_context(
+        args: HashMap<String, Vec<String>>,
+        ctx: &mut ParseContext,
+    ) -> Result<Self, ParseError> {
+        let _ = ctx;
+        Self::parse(args)
+    }
+
+    /// Parses the given Tree of Arguments into a valid instance of Self,
+    /// which is how the Arguments of all the Providers are parsed, so that
+    /// typed Values are kept intact.
+    ///
+    /// SubCategories are parsed from their Part of the Tree, which is only
+    /// borrowed, so their Arguments are neither searched for nor cloned.
+    /// The Naming-Convention inherited from the parent Struct is passed on
+    /// using [`ParseContext::rename_rule`].
+    ///
+    /// This defaults to flattening the Tree back into Arguments and passing
+    /// them to [`FromArgs::parse_with_context`]
+    fn from_value(value: &Value, ctx: &mut ParseContext) -> Result<Self, ParseError> {
+        Self::parse_with_context(value.to_args(), ctx)
+    }
+
     /// Returns a List of all Arguments that are accepted by this
     fn arguments() -> Vec<ArgumentDetail>;
+
+    /// Returns the Arguments like [`FromArgs::arguments`], while the Fields
+    /// that are not renamed in any other Way use the given Naming-Convention,
+    /// which is inherited from the parent of a SubCategory
+    ///
+    /// This defaults to [`FromArgs::arguments`], ignoring the
+    /// Naming-Convention
+    fn arguments_with_rule(rule: Option<RenameRule>) -> Vec<ArgumentDetail> {
+        let _ = rule;
+        Self::arguments()
+    }
+
+    /// Whether the Arguments are identified by their Position instead of a
+    /// Name, which is the Case for Tuple-Structs, so that
+    /// [`parse_cli`](crate::parse_cli) collects the Positional-Arguments
+    ///
+    /// This defaults to `false`
+    fn positional() -> bool {
+        false
+    }
+
+    /// Checks that the Arguments returned by [`FromArgs::arguments`] can be
+    /// told apart, which is done once before parsing, as two Fields sharing
+    /// the same Name is otherwise only noticed when it is too late
+    ///
+    /// This defaults to accepting all Arguments, while the generated Code
+    /// reports [`ParseError::DuplicateArgument`] for flattened Fields whose
+    /// Names collide
+    fn check_arguments() -> Result<(), ParseError> {
+        Ok(())
+    }
+
+    /// The Format used to split the Names of the provided Arguments, when
+    /// this is the Type being parsed and no Format was configured on the
+    /// [`ParseContext`]
+    ///
+    /// This defaults to [`KeyFormat::default`], which uses `.` as the
+    /// Separator
+    fn key_format() -> KeyFormat {
+        KeyFormat::default()
+    }
+
+    /// Checks whether the given Value is valid for the Argument with the
+    /// given full Name, like it is returned by [`FromArgs::arguments`], by
+    /// parsing it as the Type of the corresponding Field
+    ///
+    /// This returns `None` if the Argument is not known, which is also the
+    /// Default for Types that don't implement this
+    fn validate_argument(name: &str, value: &Value) -> Option<Result<(), ParseError>> {
+        let _ = (name, value);
+        None
+    }
+
+    /// Checks the given Value like [`FromArgs::validate_argument`], where the
+    /// Name is one of the Arguments returned by
+    /// [`FromArgs::arguments_with_rule`] for the given Naming-Convention
+    ///
+    /// This defaults to [`FromArgs::validate_argument`], ignoring the
+    /// Naming-Convention
+    fn validate_argument_with_rule(
+        name: &str,
+        value: &Value,
+        rule: Option<RenameRule>,
+    ) -> Option<Result<(), ParseError>> {
+        let _ = rule;
+        Self::validate_argument(name, value)
+    }
 }
 
 /// Defines the interface to parse a List-Argument Values into single Conecrete
@@ -39,29 +213,68 @@ where
     /// Parses the given Raw-Values into a single Value of the Type
     fn parse(value: Vec<String>) -> Result<Self, ParseError>;
 
-    /// Parses the given Raw-Values using [`ParseFromArgs::parse`] or if
-    /// that fails for whatever reason, it will fallback to using the
-    /// default_func
-    fn parse_with_default_fn<F>(value: Vec<String>, default_func: F) -> Self
+    /// Parses the given Raw-Values into a single Value of the Type, but skips
+    /// the malformed Parts of the Values where possible, which are instead
+    /// added to the `skipped` Errors.
+    ///
+    /// This defaults to [`ParseFromArgs::parse`] and is used for Fields marked
+    /// as `lenient`
+    fn parse_lenient(
+        value: Vec<String>,
+        skipped: &mut Vec<ParseError>,
+    ) -> Result<Self, ParseError> {
+        let _ = skipped;
+        ParseFromArgs::parse(value)
+    }
+
+    /// Parses the given Value into a single Value of the Type, which allows
+    /// Types to directly use typed Values, like Integers or nested Arrays.
+    ///
+    /// This defaults to [`ParseFromArgs::parse`] using the String-Form of
+    /// the Value
+    fn parse_value(value: Value) -> Result<Self, ParseError> {
+        ParseFromArgs::parse(value.into_strings())
+    }
+
+    /// The same as [`ParseFromArgs::parse_value`], but skips the malformed
+    /// Parts of the Value like [`ParseFromArgs::parse_lenient`].
+    ///
+    /// This defaults to [`ParseFromArgs::parse_lenient`] using the
+    /// String-Form of the Value
+    fn parse_value_lenient(
+        value: Value,
+        skipped: &mut Vec<ParseError>,
+    ) -> Result<Self, ParseError> {
+        ParseFromArgs::parse_lenient(value.into_strings(), skipped)
+    }
+
+    /// Parses the given Raw-Values using [`ParseFromArgs::parse`] or if no
+    /// Values were supplied, it will fallback to using the default_func.
+    ///
+    /// Values that were supplied but could not be parsed are still reported
+    /// as an Error
+    fn parse_with_default_fn<F>(value: Vec<String>, default_func: F) -> Result<Self, ParseError>
     where
         F: Fn() -> Self,
     {
+        let missing = value.is_empty();
         match ParseFromArgs::parse(value) {
-            Ok(v) => v,
-            Err(_) => default_func(),
+            Ok(v) => Ok(v),
+            Err(_) if missing => Ok(default_func()),
+            Err(e) => Err(e),
         }
     }
 
-    /// Parses the given Raw-Values using [`ParseFromArgs::parse`] or if that
-    /// fails for whatever reason, it will fallback to using [`Default::default`]
-    fn parse_with_default(value: Vec<String>) -> Self
+    /// Parses the given Raw-Values using [`ParseFromArgs::parse`] or if no
+    /// Values were supplied, it will fallback to using [`Default::default`].
+    ///
+    /// Values that were supplied but could not be parsed are still reported
+    /// as an Error
+    fn parse_with_default(value: Vec<String>) -> Result<Self, ParseError>
     where
         Self: Default,
     {
-        match ParseFromArgs::parse(value) {
-            Ok(v) => v,
-            Err(_) => Default::default(),
-        }
+        Self::parse_with_default_fn(value, Default::default)
     }
 }
 
@@ -83,6 +296,9 @@ impl ParseFromArgs for u64 {
             Err(_) => Err(ParseError::InvalidValue),
         }
     }
+    fn parse_value(value: Value) -> Result<Self, ParseError> {
+        parse_integer(value)
+    }
 }
 impl ParseFromArgs for u32 {
     fn parse(mut value: Vec<String>) -> Result<Self, ParseError> {
@@ -94,6 +310,9 @@ impl ParseFromArgs for u32 {
             Err(_) => Err(ParseError::InvalidValue),
         }
     }
+    fn parse_value(value: Value) -> Result<Self, ParseError> {
+        parse_integer(value)
+    }
 }
 impl ParseFromArgs for u16 {
     fn parse(mut value: Vec<String>) -> Result<Self, ParseError> {
@@ -105,6 +324,9 @@ impl ParseFromArgs for u16 {
             Err(_) => Err(ParseError::InvalidValue),
         }
     }
+    fn parse_value(value: Value) -> Result<Self, ParseError> {
+        parse_integer(value)
+    }
 }
 impl ParseFromArgs for u8 {
     fn parse(mut value: Vec<String>) -> Result<Self, ParseError> {
@@ -116,16 +338,40 @@ impl ParseFromArgs for u8 {
             Err(_) => Err(ParseError::InvalidValue),
         }
     }
+    fn parse_value(value: Value) -> Result<Self, ParseError> {
+        parse_integer(value)
+    }
 }
 impl ParseFromArgs for bool {
     fn parse(value: Vec<String>) -> Result<Self, ParseError> {
-        match value.get(0) {
+        match value.first() {
             Some(value) if value == "true" => Ok(true),
             Some(value) if value == "false" => Ok(false),
             None => Err(ParseError::MissingValue),
             _ => Err(ParseError::InvalidValue),
         }
     }
+
+    fn parse_value(value: Value) -> Result<Self, ParseError> {
+        match value.into_first() {
+            Some(Value::Bool(v)) => Ok(v),
+            Some(Value::String(v)) => ParseFromArgs::parse(vec![v]),
+            Some(_) => Err(ParseError::InvalidValue),
+            None => Err(ParseError::MissingValue),
+        }
+    }
+}
+impl ParseFromArgs for PathBuf {
+    fn parse(value: Vec<String>) -> Result<Self, ParseError> {
+        let value: String = ParseFromArgs::parse(value)?;
+        Ok(PathBuf::from(value))
+    }
+}
+impl ParseFromArgs for OsString {
+    fn parse(value: Vec<String>) -> Result<Self, ParseError> {
+        let value: String = ParseFromArgs::parse(value)?;
+        Ok(OsString::from(value))
+    }
 }
 impl<T> ParseFromArgs for Option<T>
 where
@@ -139,6 +385,38 @@ where
         let value = T::parse(value)?;
         Ok(Some(value))
     }
+
+    fn parse_lenient(
+        value: Vec<String>,
+        skipped: &mut Vec<ParseError>,
+    ) -> Result<Self, ParseError> {
+        if value.is_empty() {
+            return Err(ParseError::MissingValue);
+        }
+
+        let value = T::parse_lenient(value, skipped)?;
+        Ok(Some(value))
+    }
+    fn parse_value(value: Value) -> Result<Self, ParseError> {
+        if value.is_empty() {
+            return Err(ParseError::MissingValue);
+        }
+
+        let value = T::parse_value(value)?;
+        Ok(Some(value))
+    }
+
+    fn parse_value_lenient(
+        value: Value,
+        skipped: &mut Vec<ParseError>,
+    ) -> Result<Self, ParseError> {
+        if value.is_empty() {
+            return Err(ParseError::MissingValue);
+        }
+
+        let value = T::parse_value_lenient(value, skipped)?;
+        Ok(Some(value))
+    }
 }
 impl<T> ParseFromArgs for Vec<T>
 where
@@ -152,9 +430,76 @@ where
         value
             .into_iter()
             .map(|raw| ParseFromArgs::parse(vec![raw]))
-            .filter(|r| r.is_ok())
             .collect()
     }
+
+    fn parse_lenient(
+        value: Vec<String>,
+        skipped: &mut Vec<ParseError>,
+    ) -> Result<Self, ParseError> {
+        if value.is_empty() {
+            return Err(ParseError::MissingValue);
+        }
+
+        let mut result = Vec::with_capacity(value.len());
+        for raw in value {
+            match ParseFromArgs::parse(vec![raw]) {
+                Ok(v) => result.push(v),
+                Err(e) => skipped.push(e),
+            };
+        }
+        Ok(result)
+    }
+    fn parse_value(value: Value) -> Result<Self, ParseError> {
+        if value.is_empty() {
+            return Err(ParseError::MissingValue);
+        }
+
+        entries(value).into_iter().map(T::parse_value).collect()
+    }
+
+    fn parse_value_lenient(
+        value: Value,
+        skipped: &mut Vec<ParseError>,
+    ) -> Result<Self, ParseError> {
+        if value.is_empty() {
+            return Err(ParseError::MissingValue);
+        }
+
+        let entries = entries(value);
+        let mut result = Vec::with_capacity(entries.len());
+        for entry in entries {
+            match T::parse_value(entry) {
+                Ok(v) => result.push(v),
+                Err(e) => skipped.push(e),
+            };
+        }
+        Ok(result)
+    }
+}
+
+/// Parses an Integer from either an Integer-Value, which has to fit into the
+/// Type, or from the String-Form of the Value
+fn parse_integer<T>(value: Value) -> Result<T, ParseError>
+where
+    T: ParseFromArgs + TryFrom<i64>,
+{
+    match value.into_first() {
+        Some(Value::Integer(v)) => T::try_from(v).map_err(|_| ParseError::InvalidValue),
+        Some(Value::String(v)) => T::parse(vec![v]),
+        Some(_) => Err(ParseError::InvalidValue),
+        None => Err(ParseError::MissingValue),
+    }
+}
+
+/// The Entries of a Value that is parsed as a List, which are the Entries of
+/// an Array or otherwise the own Value itself
+fn entries(value: Value) -> Vec<Value> {
+    match value {
+        Value::Array(v) => v,
+        Value::Table(_) => value.own().cloned().map(entries).unwrap_or_default(),
+        other => vec![other],
+    }
 }
 
 #[cfg(test)]
@@ -234,6 +579,59 @@ mod tests {
         );
     }
 
+    #[test]
+    fn path_parse() {
+        assert_eq!(
+            Ok(PathBuf::from("/tmp/test")),
+            ParseFromArgs::parse(vec!["/tmp/test".to_owned()])
+        );
+        assert_eq!(
+            Result::<PathBuf, ParseError>::Err(ParseError::MissingValue),
+            ParseFromArgs::parse(Vec::new())
+        );
+    }
+
+    #[test]
+    fn vec_parse() {
+        assert_eq!(
+            Ok(vec![10u16, 20u16]),
+            ParseFromArgs::parse(vec!["10".to_owned(), "20".to_owned()])
+        );
+        assert_eq!(
+            Result::<Vec<u16>, ParseError>::Err(ParseError::InvalidValue),
+            ParseFromArgs::parse(vec!["10".to_owned(), "other".to_owned()])
+        );
+
+        let mut skipped = Vec::new();
+        assert_eq!(
+            Ok(vec![10u16]),
+            ParseFromArgs::parse_lenient(vec!["10".to_owned(), "other".to_owned()], &mut skipped)
+        );
+        assert_eq!(vec![ParseError::InvalidValue], skipped);
+    }
+
+    #[test]
+    fn parse_with_default() {
+        assert_eq!(
+            Ok(10u16),
+            ParseFromArgs::parse_with_default(vec!["10".to_owned()])
+        );
+        assert_eq!(Ok(0u16), ParseFromArgs::parse_with_default(Vec::new()));
+        assert_eq!(
+            Result::<u16, ParseError>::Err(ParseError::InvalidValue),
+            ParseFromArgs::parse_with_default(vec!["other".to_owned()])
+        );
+
+        assert_eq!(
+            Ok(5u16),
+            ParseFromArgs::parse_with_default_fn(Vec::new(), || 5)
+        );
+        assert_eq!(
+            Result::<u16, ParseError>::Err(ParseError::InvalidValue),
+            ParseFromArgs::parse_with_default_fn(vec!["other".to_owned()], || 5)
+        );
+    }
+
     #[test]
     fn bool_parse() {
         assert_eq!(
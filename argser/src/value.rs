@@ -0,0 +1,634 @@
+use std::collections::{btree_map, BTreeMap, HashMap};
+
+use crate::{
+    keys::{KeyConflict, KeyPatterns},
+    KeyFormat,
+};
+
+/// The Key under which a Table stores its own Value, for Arguments that are
+/// supplied themselves and also have nested Arguments
+const SELF_KEY: &str = "";
+
+/// A dynamically typed Value, which is used to represent Arguments whose
+/// Structure is only known at Runtime, like the Result of parsing using a
+/// [`Schema`](crate::Schema).
+///
+/// This is also the Form in which the Arguments of all the Providers are
+/// combined before being parsed, so that Providers for structured Formats
+/// can keep their typed Scalars and nested Arrays.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    /// A String, which is the Form all Arguments start out as
+    String(String),
+    /// A Boolean
+    Bool(bool),
+    /// A signed Integer
+    Integer(i64),
+    /// A Floating-Point Number
+    Float(f64),
+    /// An ordered List of Values
+    Array(Vec<Value>),
+    /// A Collection of named Values, like a SubCategory or a Map
+    Table(BTreeMap<String, Value>),
+}
+
+impl Value {
+    /// Creates a new empty Table
+    pub fn table() -> Self {
+        Self::Table(BTreeMap::new())
+    }
+
+    /// Returns the String, if this Value is a String
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::String(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    /// Returns the Boolean, if this Value is a Bool
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            Self::Bool(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    /// Returns the Integer, if this Value is an Integer
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            Self::Integer(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    /// Returns the Number, if this Value is a Float or an Integer
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            Self::Float(v) => Some(*v),
+            Self::Integer(v) => Some(*v as f64),
+            _ => None,
+        }
+    }
+
+    /// Returns the Entries, if this Value is an Array
+    pub fn as_array(&self) -> Option<&[Value]> {
+        match self {
+            Self::Array(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    /// Returns the Entries, if this Value is a Table
+    pub fn as_table(&self) -> Option<&BTreeMap<String, Value>> {
+        match self {
+            Self::Table(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    /// Looks up the Entry with the given Key, if this Value is a Table
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        self.as_table().and_then(|t| t.get(key))
+    }
+
+    /// Looks up the Entry with the given dotted Path, like `db.host`, by
+    /// descending into the nested Tables
+    pub fn pointer(&self, path: &str) -> Option<&Value> {
+        let format = KeyFormat::default();
+        if format.is_single(path) {
+            return self.get(path);
+        }
+        format
+            .split(path)
+            .iter()
+            .try_fold(self, |current, key| current.get(key))
+    }
+
+    /// Looks up the Entry with the given Path, which is already split into
+    /// its Segments, by descending into the nested Tables, while normalizing
+    /// the Segments according to the given Format
+    pub fn pointer_path(&self, path: &[&str], format: &KeyFormat) -> Option<&Value> {
+        path.iter()
+            .try_fold(self, |current, key| current.get(&format.normalize(key)))
+    }
+
+    /// Creates an empty Value, which is used for Arguments that were not
+    /// supplied at all
+    pub fn empty() -> Self {
+        Self::Array(Vec::new())
+    }
+
+    /// Whether this Value does not contain anything that could be parsed,
+    /// which is the case for empty Arrays and Tables without an own Value
+    pub fn is_empty(&self) -> bool {
+        match self {
+            Self::Array(v) => v.is_empty(),
+            Self::Table(t) => t.get(SELF_KEY).map(Value::is_empty).unwrap_or(true),
+            _ => false,
+        }
+    }
+
+    /// Builds a Tree of Tables from the given flat Arguments, by splitting
+    /// their Names on `.`, see [`Value::from_args_with`]
+    pub fn from_args(args: HashMap<String, Vec<String>>) -> Self {
+        Self::from_args_with(args, &KeyFormat::default())
+    }
+
+    /// Builds a Tree of Tables from the given flat Arguments, by splitting
+    /// their Names according to the given Format
+    ///
+    /// An Argument with multiple Values becomes an Array of Strings, while
+    /// an Argument with a single Value becomes a String. If an Argument is
+    /// both supplied itself and has nested Arguments, like `db.password` and
+    /// `db.password.file`, its own Value is stored under the empty Key in
+    /// the Table of its nested Arguments.
+    pub fn from_args_with(args: HashMap<String, Vec<String>>, format: &KeyFormat) -> Self {
+        let mut result = Self::table();
+        for (name, mut values) in args {
+            let value = match values.len() {
+                1 => Self::String(values.remove(0)),
+                _ => Self::Array(values.into_iter().map(Self::String).collect()),
+            };
+            result.insert_owned(name, value, format);
+        }
+        result
+    }
+
+    /// Builds a Tree of Tables from the given Argument-Pairs, like they are
+    /// returned by an [`ArgProvider`](crate::ArgProvider), by splitting their
+    /// Names on `.`, see [`Value::from_pairs_with`]
+    pub fn from_pairs<I>(pairs: I) -> Self
+    where
+        I: IntoIterator<Item = (String, String)>,
+    {
+        Self::from_pairs_with(pairs, &KeyFormat::default())
+    }
+
+    /// Builds a Tree of Tables from the given Argument-Pairs, by splitting
+    /// their Names according to the given Format, where repeated Arguments
+    /// are collected into an Array
+    pub fn from_pairs_with<I>(pairs: I, format: &KeyFormat) -> Self
+    where
+        I: IntoIterator<Item = (String, String)>,
+    {
+        let mut result = Self::table();
+        for (name, value) in pairs {
+            result.insert_owned(name, Self::String(value), format);
+        }
+        result
+    }
+
+    /// Merges the given Value into the Entry at the given dotted Path, while
+    /// creating all the Tables along the Path that don't exist yet
+    pub fn insert(&mut self, path: &str, value: Value) {
+        self.insert_owned(path.to_owned(), value, &KeyFormat::default());
+    }
+
+    /// Merges the given Value into the Entry at the given Path, which is
+    /// split according to the given Format. Names with a single Segment are
+    /// used as the Key directly, without allocating them again.
+    fn insert_owned(&mut self, path: String, value: Value, format: &KeyFormat) {
+        if format.is_single(&path) {
+            self.merge_entry(path, value);
+            return;
+        }
+
+        let mut segments = format.split(&path);
+        let key = segments.pop().unwrap_or_default();
+
+        let mut current = self;
+        for segment in segments.iter() {
+            current = current.entry(segment);
+        }
+        current.merge_entry(key, value);
+    }
+
+    /// Merges the given Value into the Entry with the given Key
+    fn merge_entry(&mut self, key: String, value: Value) {
+        match self.as_table_mut().entry(key) {
+            btree_map::Entry::Occupied(mut entry) => entry.get_mut().merge(value),
+            btree_map::Entry::Vacant(entry) => {
+                entry.insert(value);
+            }
+        };
+    }
+
+    /// Returns the Entry with the given Key, which is created as an empty
+    /// Value if it does not exist yet. A Value that is not a Table is turned
+    /// into one, where the Value is kept as its own Value.
+    fn entry(&mut self, key: &str) -> &mut Value {
+        let table = self.as_table_mut();
+        // Only allocate the Key when the Entry is actually new
+        if !table.contains_key(key) {
+            table.insert(key.to_owned(), Self::empty());
+        }
+        table.get_mut(key).expect("The Entry was just inserted")
+    }
+
+    /// Returns the Entries of this Value, which is turned into a Table first,
+    /// if it is not one already, while keeping its own Value
+    fn as_table_mut(&mut self) -> &mut BTreeMap<String, Value> {
+        if !matches!(self, Self::Table(_)) {
+            let own = std::mem::replace(self, Self::table());
+            if !own.is_empty() {
+                self.insert_own(own);
+            }
+        }
+
+        match self {
+            Self::Table(t) => t,
+            _ => unreachable!("The Value was turned into a Table"),
+        }
+    }
+
+    fn insert_own(&mut self, value: Value) {
+        if let Self::Table(t) = self {
+            t.entry(SELF_KEY.to_owned())
+                .or_insert_with(Self::empty)
+                .merge(value);
+        }
+    }
+
+    /// Merges the other Value into this one, where Tables are merged Entry by
+    /// Entry and all other Values are combined into an Array, which contains
+    /// the Values of this one first.
+    ///
+    /// This is used to combine the Values of multiple Providers, so the
+    /// Values of earlier Providers take precedence for single Values.
+    pub fn merge(&mut self, other: Value) {
+        match other {
+            Self::Table(entries) if self.is_vacant() => {
+                *self = Self::Table(entries);
+            }
+            Self::Table(entries) => {
+                for (key, value) in entries {
+                    if key == SELF_KEY {
+                        self.insert_own_or_merge(value);
+                        continue;
+                    }
+
+                    self.merge_entry(key, value);
+                }
+            }
+            other => self.insert_own_or_merge(other),
+        }
+    }
+
+    /// Whether this Value contains nothing at all, not even nested Entries
+    fn is_vacant(&self) -> bool {
+        match self {
+            Self::Array(v) => v.is_empty(),
+            Self::Table(t) => t.is_empty(),
+            _ => false,
+        }
+    }
+
+    fn insert_own_or_merge(&mut self, value: Value) {
+        match self {
+            Self::Table(_) => self.insert_own(value),
+            current if current.is_empty() => *current = value,
+            current => {
+                let mut values = std::mem::replace(current, Self::empty()).into_values();
+                values.extend(value.into_values());
+                *current = Self::Array(values);
+            }
+        }
+    }
+
+    /// The Values contained in this one, which are the Entries of an Array
+    /// or the Value itself
+    fn into_values(self) -> Vec<Value> {
+        match self {
+            Self::Array(v) => v,
+            other => vec![other],
+        }
+    }
+
+    /// The Value of this Node itself, which is the Value stored under the
+    /// empty Key for Tables and the Value itself for everything else
+    pub fn own(&self) -> Option<&Value> {
+        match self {
+            Self::Table(t) => t.get(SELF_KEY),
+            other => Some(other),
+        }
+    }
+
+    /// Looks up the own Value of the Entry with the given dotted Path, while
+    /// returning an empty Value if there is none
+    pub fn lookup(&self, path: &str) -> Value {
+        Self::own_or_empty(self.pointer(path))
+    }
+
+    /// Looks up the own Value of the Entry with the given Path, which is
+    /// already split into its Segments and normalized according to the given
+    /// Format, while returning an empty Value if there is none
+    pub fn lookup_path(&self, path: &[&str], format: &KeyFormat) -> Value {
+        Self::own_or_empty(self.pointer_path(path, format))
+    }
+
+    pub(crate) fn own_or_empty(entry: Option<&Value>) -> Value {
+        entry
+            .and_then(Value::own)
+            .cloned()
+            .unwrap_or_else(Self::empty)
+    }
+
+    /// Returns the first Value contained in this one, which is used by all
+    /// the Types that only ever consider a single Value
+    pub fn into_first(self) -> Option<Value> {
+        match self {
+            Self::Array(v) => v.into_iter().find_map(Value::into_first),
+            Self::Table(mut t) => t.remove(SELF_KEY).and_then(Value::into_first),
+            other => Some(other),
+        }
+    }
+
+    /// Turns this Value back into the raw String-Values, which is used for
+    /// Types that are only parsed from Strings
+    pub fn into_strings(self) -> Vec<String> {
+        match self {
+            Self::String(v) => vec![v],
+            Self::Bool(v) => vec![v.to_string()],
+            Self::Integer(v) => vec![v.to_string()],
+            Self::Float(v) => vec![v.to_string()],
+            Self::Array(v) => v.into_iter().flat_map(Value::into_strings).collect(),
+            Self::Table(mut t) => t
+                .remove(SELF_KEY)
+                .map(Value::into_strings)
+                .unwrap_or_default(),
+        }
+    }
+
+    /// All the Entries of a Table, with nested Tables resolved into Keys
+    /// joined by the Separator of the given Format, like `api.example.com`,
+    /// which is used for Maps whose Keys may themselves contain the Separator
+    pub fn flat_entries(&self, format: &KeyFormat) -> Vec<(String, &Value)> {
+        let mut result = Vec::new();
+        if let Self::Table(t) = self {
+            for (key, value) in t {
+                if key == SELF_KEY {
+                    continue;
+                }
+
+                if let Some(own) = value.own().filter(|v| !v.is_empty()) {
+                    result.push((key.clone(), own));
+                }
+                for (sub_key, sub_value) in value.flat_entries(format) {
+                    let key = format!("{}{}{}", key, format.separator(), sub_key);
+                    result.push((key, sub_value));
+                }
+            }
+        }
+        result
+    }
+
+    /// Normalizes the Keys of all the nested Tables according to the given
+    /// Format, while merging the Entries whose Keys are normalized to the
+    /// same Key. Every such Pair of Entries is recorded in `conflicts`.
+    ///
+    /// The Keys of Maps are kept as they were provided, if the Names of the
+    /// Arguments are known, while otherwise every Key is normalized
+    pub(crate) fn normalize_keys(
+        self,
+        format: &KeyFormat,
+        patterns: Option<&KeyPatterns>,
+        conflicts: &mut Vec<KeyConflict>,
+    ) -> Self {
+        self.normalize_at(format, patterns, &mut Vec::new(), "", conflicts)
+    }
+
+    fn normalize_at(
+        self,
+        format: &KeyFormat,
+        patterns: Option<&KeyPatterns>,
+        path: &mut Vec<String>,
+        name: &str,
+        conflicts: &mut Vec<KeyConflict>,
+    ) -> Self {
+        match self {
+            Self::Table(entries) => {
+                let mut result = Self::table();
+                let mut spellings: HashMap<String, String> = HashMap::new();
+                for (key, value) in entries {
+                    let raw_name = match key.as_str() {
+                        SELF_KEY => name.to_owned(),
+                        key => format.join(name, key),
+                    };
+                    let normalized = match patterns {
+                        Some(p) if p.is_map_key(path, &key, format) => key.clone(),
+                        _ => format.normalize(&key).into_owned(),
+                    };
+                    match spellings.get(&normalized) {
+                        Some(first) => {
+                            let mut conflict_path = path.clone();
+                            conflict_path.push(normalized.clone());
+                            conflicts.push(KeyConflict {
+                                path: conflict_path,
+                                first: format.join(name, first),
+                                second: raw_name.clone(),
+                            });
+                        }
+                        None => {
+                            spellings.insert(normalized.clone(), key);
+                        }
+                    };
+
+                    path.push(normalized.clone());
+                    let value = value.normalize_at(format, patterns, path, &raw_name, conflicts);
+                    path.pop();
+
+                    result.merge_entry(normalized, value);
+                }
+                result
+            }
+            Self::Array(values) => {
+                let mut result = Vec::with_capacity(values.len());
+                for (index, value) in values.into_iter().enumerate() {
+                    let index = index.to_string();
+                    let raw_name = format.join(name, &index);
+
+                    path.push(index);
+                    result.push(value.normalize_at(format, patterns, path, &raw_name, conflicts));
+                    path.pop();
+                }
+                Self::Array(result)
+            }
+            other => other,
+        }
+    }
+
+    /// Turns this Value back into flat Argument-Pairs, which is the inverse
+    /// of [`Value::from_pairs`].
+    ///
+    /// Arrays of Tables or Arrays are turned into indexed Arguments, like
+    /// `servers.0.host`, while all other Arrays are turned into repeated
+    /// Arguments. Keys that contain a `.` are quoted.
+    pub fn to_pairs(&self) -> Vec<(String, String)> {
+        let mut result = Vec::new();
+        self.collect_pairs(String::new(), &mut result);
+        result
+    }
+
+    fn collect_pairs(&self, name: String, result: &mut Vec<(String, String)>) {
+        let join = |key: &str| match key {
+            SELF_KEY => name.clone(),
+            key => KeyFormat::default().join(&name, key),
+        };
+
+        match self {
+            Self::Table(t) => {
+                for (key, value) in t {
+                    value.collect_pairs(join(key), result);
+                }
+            }
+            Self::Array(v)
+                if v.iter()
+                    .any(|e| matches!(e, Self::Table(_) | Self::Array(_))) =>
+            {
+                for (index, value) in v.iter().enumerate() {
+                    value.collect_pairs(join(&index.to_string()), result);
+                }
+            }
+            other => {
+                for value in other.clone().into_strings() {
+                    result.push((name.clone(), value));
+                }
+            }
+        }
+    }
+
+    /// Turns this Value back into flat Arguments, which is used to parse
+    /// Types that only implement [`FromArgs::parse`](crate::FromArgs::parse)
+    pub fn to_args(&self) -> HashMap<String, Vec<String>> {
+        let mut result: HashMap<String, Vec<String>> = HashMap::new();
+        for (name, value) in self.to_pairs() {
+            result.entry(name).or_default().push(value);
+        }
+        result
+    }
+}
+
+impl From<String> for Value {
+    fn from(v: String) -> Self {
+        Self::String(v)
+    }
+}
+impl From<&str> for Value {
+    fn from(v: &str) -> Self {
+        Self::String(v.to_owned())
+    }
+}
+impl From<bool> for Value {
+    fn from(v: bool) -> Self {
+        Self::Bool(v)
+    }
+}
+impl From<i64> for Value {
+    fn from(v: i64) -> Self {
+        Self::Integer(v)
+    }
+}
+impl From<f64> for Value {
+    fn from(v: f64) -> Self {
+        Self::Float(v)
+    }
+}
+impl<T> From<Vec<T>> for Value
+where
+    T: Into<Value>,
+{
+    fn from(v: Vec<T>) -> Self {
+        Self::Array(v.into_iter().map(Into::into).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_pairs() {
+        let value = Value::from_pairs(vec![
+            ("db.host".to_owned(), "localhost".to_owned()),
+            ("db.password".to_owned(), "secret".to_owned()),
+            ("db.password.file".to_owned(), "/run/secret".to_owned()),
+            ("tag".to_owned(), "a".to_owned()),
+            ("tag".to_owned(), "b".to_owned()),
+        ]);
+
+        assert_eq!(Some(&Value::from("localhost")), value.pointer("db.host"));
+        assert_eq!(Value::from("secret"), value.lookup("db.password"));
+        assert_eq!(Value::from("/run/secret"), value.lookup("db.password.file"));
+        assert_eq!(Value::from(vec!["a", "b"]), value.lookup("tag"));
+        assert!(value.lookup("db").is_empty());
+        assert!(value.lookup("other").is_empty());
+    }
+
+    #[test]
+    fn merge() {
+        let mut value = Value::table();
+        value.insert("port", Value::Integer(80));
+        value.insert("servers", Value::from(vec![Value::from(vec![1i64, 2])]));
+
+        let mut other = Value::table();
+        other.insert("port", Value::from("8080"));
+        other.insert("port.file", Value::from("/tmp/port"));
+        other.insert("servers", Value::from(vec![Value::from(vec![3i64])]));
+        value.merge(other);
+
+        assert_eq!(
+            Value::from(vec![Value::Integer(80), Value::from("8080")]),
+            value.lookup("port")
+        );
+        assert_eq!(Value::from("/tmp/port"), value.lookup("port.file"));
+        assert_eq!(
+            Value::from(vec![Value::from(vec![1i64, 2]), Value::from(vec![3i64])]),
+            value.lookup("servers")
+        );
+    }
+
+    #[test]
+    fn flat_entries() {
+        let value = Value::from_pairs(vec![
+            ("api.example.com".to_owned(), "1".to_owned()),
+            ("api".to_owned(), "2".to_owned()),
+        ]);
+
+        assert_eq!(
+            vec![
+                ("api".to_owned(), &Value::from("2")),
+                ("api.example.com".to_owned(), &Value::from("1")),
+            ],
+            value.flat_entries(&KeyFormat::default())
+        );
+    }
+
+    #[test]
+    fn args_round_trip() {
+        let mut args = HashMap::new();
+        args.insert("db.host".to_owned(), vec!["localhost".to_owned()]);
+        args.insert("db".to_owned(), vec!["main".to_owned()]);
+        args.insert(
+            "tags".to_owned(),
+            vec!["first".to_owned(), "second".to_owned()],
+        );
+        args.insert(
+            "hosts.\"api.example.com\"".to_owned(),
+            vec!["80".to_owned()],
+        );
+
+        assert_eq!(args, Value::from_args(args.clone()).to_args());
+    }
+
+    #[test]
+    fn into_strings() {
+        let value = Value::from(vec![
+            Value::Integer(1),
+            Value::Bool(true),
+            Value::Float(0.5),
+        ]);
+        assert_eq!(vec!["1", "true", "0.5"], value.clone().into_strings());
+        assert_eq!(Some(Value::Integer(1)), value.into_first());
+    }
+}
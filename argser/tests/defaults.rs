@@ -0,0 +1,159 @@
+use std::path::PathBuf;
+
+use argser::{argser, FromArgs, ParseError};
+
+#[test]
+fn missing_uses_default() {
+    #[argser]
+    #[derive(Debug, PartialEq)]
+    struct Options {
+        #[argser(default)]
+        port: u16,
+        #[argser(default_func(default_host))]
+        host: String,
+    }
+
+    fn default_host() -> String {
+        "localhost".to_owned()
+    }
+
+    let fixed_provider = argser::provider::Fixed::empty();
+
+    let expected = Options {
+        port: 0,
+        host: "localhost".to_owned(),
+    };
+    assert_eq!(
+        Ok(expected),
+        argser::parse_args_from_providers(&[&fixed_provider])
+    );
+}
+
+#[test]
+fn invalid_with_default_errors() {
+    #[argser]
+    #[derive(Debug, PartialEq)]
+    struct Options {
+        #[argser(default)]
+        port: u16,
+    }
+
+    let fixed_provider = {
+        let mut tmp = argser::provider::Fixed::empty();
+
+        tmp.add_arg("port", "80a");
+
+        tmp
+    };
+
+    assert_eq!(
+        Result::<Options, _>::Err(ParseError::InvalidArgument {
+            name: "port".to_owned(),
+            error: Box::new(ParseError::InvalidValue),
+        }),
+        argser::parse_args_from_providers(&[&fixed_provider])
+    );
+}
+
+#[test]
+fn invalid_with_default_on_invalid() {
+    #[argser]
+    #[derive(Debug, PartialEq)]
+    struct Options {
+        #[argser(default_func(default_port), default_on_invalid)]
+        port: u16,
+    }
+
+    fn default_port() -> u16 {
+        8080
+    }
+
+    let fixed_provider = {
+        let mut tmp = argser::provider::Fixed::empty();
+
+        tmp.add_arg("port", "80a");
+
+        tmp
+    };
+
+    let expected = Options { port: 8080 };
+    assert_eq!(
+        Ok(expected),
+        argser::parse_args_from_providers(&[&fixed_provider])
+    );
+}
+
+#[test]
+fn missing_without_default() {
+    #[argser]
+    #[derive(Debug, PartialEq)]
+    struct Options {
+        port: u16,
+    }
+
+    let fixed_provider = argser::provider::Fixed::empty();
+
+    assert_eq!(
+        Result::<Options, _>::Err(ParseError::MissingParam {
+            name: "port".to_owned(),
+        }),
+        argser::parse_args_from_providers(&[&fixed_provider])
+    );
+}
+
+#[test]
+fn literal_and_expr_defaults() {
+    #[argser]
+    #[derive(Debug, PartialEq)]
+    struct Options {
+        #[argser(default = 8080)]
+        port: u16,
+        #[argser(default = "localhost")]
+        host: String,
+        #[argser(default_expr = std::env::temp_dir().join("argser"))]
+        cache: PathBuf,
+    }
+
+    let fixed_provider = argser::provider::Fixed::empty();
+
+    let expected = Options {
+        port: 8080,
+        host: "localhost".to_owned(),
+        cache: std::env::temp_dir().join("argser"),
+    };
+    assert_eq!(
+        Ok(expected),
+        argser::parse_args_from_providers(&[&fixed_provider])
+    );
+
+    let args = Options::arguments();
+    assert_eq!(Some("8080".to_owned()), args[0].default);
+    assert_eq!(Some("localhost".to_owned()), args[1].default);
+    assert_eq!(
+        Some("std :: env :: temp_dir().join(\"argser\")".to_owned()),
+        args[2].default
+    );
+    assert!(args.iter().all(|a| !a.required));
+
+    assert!(argser::help::<Options>().contains("-port  [default: 8080]\n"));
+}
+
+#[test]
+fn invalid_literal_default() {
+    #[argser]
+    #[derive(Debug, PartialEq)]
+    struct Options {
+        #[argser(default = "http")]
+        port: u16,
+    }
+
+    let fixed_provider = argser::provider::Fixed::empty();
+
+    assert_eq!(
+        Result::<Options, _>::Err(ParseError::InvalidDefault {
+            name: "port".to_owned(),
+            error: Box::new(ParseError::InvalidValue),
+        }),
+        argser::parse_args_from_providers(&[&fixed_provider])
+    );
+}
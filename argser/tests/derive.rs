@@ -0,0 +1,91 @@
+use std::collections::{BTreeMap, HashMap};
+
+use argser::FromArgs;
+
+#[test]
+fn derive_keeps_struct() {
+    #[derive(Debug, PartialEq, FromArgs)]
+    #[argser(rename_all = "kebab-case")]
+    struct Options {
+        max_conns: u16,
+        #[argser(map)]
+        limits: HashMap<String, u16>,
+        #[argser(map(list))]
+        routes: BTreeMap<u16, Vec<String>>,
+        #[argser(list(subcategory))]
+        upstream: Vec<Upstream>,
+    }
+
+    #[derive(Debug, PartialEq, FromArgs)]
+    struct Upstream {
+        host: String,
+    }
+
+    let fixed_provider = {
+        let mut tmp = argser::provider::Fixed::empty();
+
+        tmp.add_arg("max-conns", "10");
+        tmp.add_arg("limits.cpu", "2");
+        tmp.add_arg("routes.80.0", "/");
+        tmp.add_arg("routes.80.1", "/api");
+        tmp.add_arg("upstream.0.host", "example.com");
+
+        tmp
+    };
+
+    let expected = Options {
+        max_conns: 10,
+        limits: {
+            let mut tmp = HashMap::new();
+            tmp.insert("cpu".to_owned(), 2);
+            tmp
+        },
+        routes: {
+            let mut tmp = BTreeMap::new();
+            tmp.insert(80, vec!["/".to_owned(), "/api".to_owned()]);
+            tmp
+        },
+        upstream: vec![Upstream {
+            host: "example.com".to_owned(),
+        }],
+    };
+    assert_eq!(
+        Ok(expected),
+        argser::parse_args_from_providers(&[&fixed_provider])
+    );
+
+    let names: Vec<_> = Options::arguments().into_iter().map(|a| a.name).collect();
+    assert_eq!(
+        vec![
+            "max-conns",
+            "limits.{name}",
+            "routes.{name}.{index}",
+            "upstream.{index}.host"
+        ],
+        names
+    );
+}
+
+#[test]
+fn derive_newtype() {
+    #[derive(Debug, PartialEq, FromArgs)]
+    struct Port(u16);
+
+    #[derive(Debug, PartialEq, FromArgs)]
+    struct Options {
+        port: Port,
+    }
+
+    let fixed_provider = {
+        let mut tmp = argser::provider::Fixed::empty();
+
+        tmp.add_arg("port", "8080");
+
+        tmp
+    };
+
+    assert_eq!(
+        Ok(Options { port: Port(8080) }),
+        argser::parse_args_from_providers(&[&fixed_provider])
+    );
+}
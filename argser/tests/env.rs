@@ -0,0 +1,78 @@
+use std::collections::HashMap;
+
+use argser::{argser, FromArgs, ParseContext};
+
+#[test]
+fn env_fallback() {
+    #[argser]
+    #[derive(Debug, PartialEq)]
+    struct Options {
+        #[argser(env = "DATABASE_URL")]
+        database: String,
+        #[argser(env = "PORT", default = 8080)]
+        port: u16,
+    }
+
+    let fixed_provider = argser::provider::Fixed::empty();
+
+    let mut ctx = ParseContext::new();
+    ctx.set_env({
+        let mut tmp = HashMap::new();
+        tmp.insert("DATABASE_URL".to_owned(), "postgres://localhost".to_owned());
+        tmp
+    });
+
+    let expected = Options {
+        database: "postgres://localhost".to_owned(),
+        port: 8080,
+    };
+    assert_eq!(
+        Ok(expected),
+        argser::parse_args_from_providers_with_context(&[&fixed_provider], &mut ctx)
+    );
+
+    let expected_args = vec![
+        argser::ArgumentDetail::new("database")
+            .required(true)
+            .env("DATABASE_URL"),
+        argser::ArgumentDetail::new("port")
+            .default("8080")
+            .env("PORT"),
+    ];
+    assert_eq!(expected_args, Options::arguments());
+    assert_eq!(
+        "-database [required] [env: DATABASE_URL]\n-port     [env: PORT] [default: 8080]\n",
+        argser::help::<Options>()
+    );
+}
+
+#[test]
+fn provider_overrides_env() {
+    #[argser]
+    #[derive(Debug, PartialEq)]
+    struct Options {
+        #[argser(env = "PORT")]
+        port: u16,
+    }
+
+    let fixed_provider = {
+        let mut tmp = argser::provider::Fixed::empty();
+
+        tmp.add_arg("port", "123");
+
+        tmp
+    };
+
+    let mut ctx = ParseContext::new();
+    ctx.set_env({
+        let mut tmp = HashMap::new();
+        tmp.insert("PORT".to_owned(), "8080".to_owned());
+        tmp
+    });
+
+    let expected = Options { port: 123 };
+    assert_eq!(
+        Ok(expected),
+        argser::parse_args_from_providers_with_context(&[&fixed_provider], &mut ctx)
+    );
+}
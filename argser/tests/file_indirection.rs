@@ -0,0 +1,107 @@
+use std::path::PathBuf;
+
+use argser::argser;
+
+fn secret_file(name: &str, content: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!(
+        "argser-file-indirection-{}-{}",
+        name,
+        std::process::id()
+    ));
+    std::fs::write(&path, content).unwrap();
+    path
+}
+
+#[test]
+fn value_from_file() {
+    #[argser]
+    #[derive(Debug, PartialEq)]
+    struct Options {
+        #[argser(file_indirection, rename("DB_PASSWORD"))]
+        password: String,
+        #[argser(file_indirection)]
+        user: String,
+    }
+
+    let path = secret_file("value", "secret-password\n");
+
+    let fixed_provider = {
+        let mut tmp = argser::provider::Fixed::empty();
+        tmp.add_arg("DB_PASSWORD_FILE", path.to_str().unwrap());
+        tmp.add_arg("user", "test-user");
+        tmp
+    };
+
+    let expected = Options {
+        password: "secret-password".to_owned(),
+        user: "test-user".to_owned(),
+    };
+    assert_eq!(
+        Ok(expected),
+        argser::parse_args_from_providers(&[&fixed_provider])
+    );
+
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn subcategory_dotted_file() {
+    #[argser]
+    #[derive(Debug, PartialEq)]
+    struct Options {
+        #[argser(subcategory)]
+        db: Database,
+    }
+
+    #[argser]
+    #[derive(Debug, PartialEq)]
+    struct Database {
+        #[argser(file_indirection)]
+        password: String,
+    }
+
+    let path = secret_file("dotted", "secret-password");
+
+    let fixed_provider = {
+        let mut tmp = argser::provider::Fixed::empty();
+        tmp.add_arg("db.password.file", path.to_str().unwrap());
+        tmp
+    };
+
+    let expected = Options {
+        db: Database {
+            password: "secret-password".to_owned(),
+        },
+    };
+    assert_eq!(
+        Ok(expected),
+        argser::parse_args_from_providers(&[&fixed_provider])
+    );
+
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn missing_file() {
+    #[argser]
+    #[derive(Debug, PartialEq)]
+    struct Options {
+        #[argser(file_indirection)]
+        password: String,
+    }
+
+    let fixed_provider = {
+        let mut tmp = argser::provider::Fixed::empty();
+        tmp.add_arg("password_FILE", "/argser/does/not/exist");
+        tmp
+    };
+
+    let result: Result<Options, _> = argser::parse_args_from_providers(&[&fixed_provider]);
+    match result {
+        Err(argser::ParseError::InvalidFile { name, path, .. }) => {
+            assert_eq!("password_FILE", name);
+            assert_eq!("/argser/does/not/exist", path);
+        }
+        other => panic!("Expected InvalidFile-Error but got {:?}", other),
+    };
+}
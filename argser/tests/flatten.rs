@@ -0,0 +1,131 @@
+use argser::{argser, FromArgs, ParseError};
+
+#[argser(rename_all = "kebab-case")]
+#[derive(Debug, PartialEq)]
+struct Logging {
+    log_level: String,
+    #[argser(default)]
+    log_json: bool,
+}
+
+#[test]
+fn flatten_into_parent() {
+    #[argser]
+    #[derive(Debug, PartialEq)]
+    struct Options {
+        name: String,
+        #[argser(flatten)]
+        logging: Logging,
+    }
+
+    let fixed_provider = {
+        let mut tmp = argser::provider::Fixed::empty();
+
+        tmp.add_arg("name", "test-name");
+        tmp.add_arg("log-level", "debug");
+
+        tmp
+    };
+
+    let expected = Options {
+        name: "test-name".to_owned(),
+        logging: Logging {
+            log_level: "debug".to_owned(),
+            log_json: false,
+        },
+    };
+    assert_eq!(
+        Ok(expected),
+        argser::parse_args_from_providers(&[&fixed_provider])
+    );
+
+    let names: Vec<_> = Options::arguments().into_iter().map(|a| a.name).collect();
+    assert_eq!(vec!["name", "log-level", "log-json"], names);
+}
+
+#[test]
+fn flatten_with_prefix() {
+    #[argser(prefix = "app")]
+    #[derive(Debug, PartialEq)]
+    struct Options {
+        #[argser(flatten)]
+        logging: Logging,
+    }
+
+    let fixed_provider = {
+        let mut tmp = argser::provider::Fixed::empty();
+
+        tmp.add_arg("app.log-level", "info");
+
+        tmp
+    };
+
+    let expected = Options {
+        logging: Logging {
+            log_level: "info".to_owned(),
+            log_json: false,
+        },
+    };
+    assert_eq!(
+        Ok(expected),
+        argser::parse_args_from_providers(&[&fixed_provider])
+    );
+
+    let names: Vec<_> = Options::arguments().into_iter().map(|a| a.name).collect();
+    assert_eq!(vec!["app.log-level", "app.log-json"], names);
+}
+
+#[argser]
+#[derive(Debug, PartialEq)]
+struct Colliding {
+    #[argser(rename("log-level"))]
+    level: String,
+    #[argser(flatten)]
+    logging: Logging,
+}
+
+#[test]
+fn flatten_collision_parse() {
+    let fixed_provider = {
+        let mut tmp = argser::provider::Fixed::empty();
+
+        tmp.add_arg("log-level", "info");
+
+        tmp
+    };
+
+    assert_eq!(
+        Result::<Colliding, _>::Err(ParseError::DuplicateArgument {
+            name: "log-level".to_owned(),
+        }),
+        argser::parse_args_from_providers(&[&fixed_provider])
+    );
+
+    let names: Vec<_> = Colliding::arguments().into_iter().map(|a| a.name).collect();
+    assert_eq!(vec!["log-level", "log-level", "log-json"], names);
+}
+
+#[test]
+fn flatten_collision_nested() {
+    #[argser]
+    #[derive(Debug, PartialEq)]
+    struct Options {
+        #[argser(subcategory)]
+        app: Colliding,
+    }
+
+    let fixed_provider = {
+        let mut tmp = argser::provider::Fixed::empty();
+
+        tmp.add_arg("app.log-level", "info");
+
+        tmp
+    };
+
+    assert_eq!(
+        Result::<Options, _>::Err(ParseError::DuplicateArgument {
+            name: "app.log-level".to_owned(),
+        }),
+        argser::parse_args_from_providers(&[&fixed_provider])
+    );
+}
@@ -0,0 +1,95 @@
+use std::{borrow::Cow, marker::PhantomData};
+
+use argser::{argser, FromArgs, ParseError, ParseFromArgs};
+
+#[argser]
+#[derive(Debug, PartialEq)]
+struct Pool<T> {
+    size: u16,
+    #[argser(flatten)]
+    backend: T,
+}
+
+#[argser]
+#[derive(Debug, PartialEq)]
+struct Postgres {
+    url: String,
+}
+
+#[test]
+fn generic_subcategory() {
+    let fixed_provider = {
+        let mut tmp = argser::provider::Fixed::empty();
+
+        tmp.add_arg("size", "4");
+        tmp.add_arg("url", "postgres://localhost");
+
+        tmp
+    };
+
+    let expected = Pool {
+        size: 4,
+        backend: Postgres {
+            url: "postgres://localhost".to_owned(),
+        },
+    };
+    assert_eq!(
+        Ok(expected),
+        argser::parse_args_from_providers(&[&fixed_provider])
+    );
+
+    let names: Vec<_> = Pool::<Postgres>::arguments()
+        .into_iter()
+        .map(|a| a.name)
+        .collect();
+    assert_eq!(vec!["size", "url"], names);
+}
+
+#[test]
+fn generic_values_and_lifetimes() {
+    #[derive(Debug, PartialEq)]
+    struct Name<'a>(Cow<'a, str>);
+
+    impl<'a> ParseFromArgs for Name<'a> {
+        fn parse(value: Vec<String>) -> Result<Self, ParseError> {
+            let value: String = ParseFromArgs::parse(value)?;
+            Ok(Name(Cow::Owned(value)))
+        }
+    }
+
+    #[argser]
+    #[derive(Debug, PartialEq)]
+    struct Options<'a, T, U>
+    where
+        U: Default,
+    {
+        name: Name<'a>,
+        #[argser(map)]
+        limits: T,
+        #[argser(skip)]
+        marker: PhantomData<U>,
+    }
+
+    let fixed_provider = {
+        let mut tmp = argser::provider::Fixed::empty();
+
+        tmp.add_arg("name", "test-name");
+        tmp.add_arg("limits.cpu", "2");
+
+        tmp
+    };
+
+    let expected = Options::<u16, ()> {
+        name: Name(Cow::Borrowed("test-name")),
+        limits: {
+            let mut tmp = std::collections::HashMap::new();
+            tmp.insert("cpu".to_owned(), 2);
+            tmp
+        },
+        marker: PhantomData,
+    };
+    assert_eq!(
+        Ok(expected),
+        argser::parse_args_from_providers(&[&fixed_provider])
+    );
+}
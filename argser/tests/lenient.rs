@@ -0,0 +1,165 @@
+use std::collections::HashMap;
+
+use argser::{argser, ParseContext, ParseError, Warning};
+
+#[test]
+fn strict_map_errors() {
+    #[argser]
+    #[derive(Debug, PartialEq)]
+    struct Options {
+        #[argser(map)]
+        con: u16,
+    }
+
+    let fixed_provider = {
+        let mut tmp = argser::provider::Fixed::empty();
+
+        tmp.add_arg("con.port", "abc");
+
+        tmp
+    };
+
+    assert_eq!(
+        Result::<Options, _>::Err(ParseError::InvalidArgument {
+            name: "con.port".to_owned(),
+            error: Box::new(ParseError::InvalidValue),
+        }),
+        argser::parse_args_from_providers(&[&fixed_provider])
+    );
+}
+
+#[test]
+fn strict_map_subcategory_errors() {
+    #[argser]
+    #[derive(Debug, PartialEq)]
+    struct Options {
+        #[argser(map(subcategory))]
+        con: Con,
+    }
+
+    #[argser]
+    #[derive(Debug, PartialEq)]
+    struct Con {
+        ip: String,
+        #[argser(map)]
+        ports: u16,
+    }
+
+    let fixed_provider = {
+        let mut tmp = argser::provider::Fixed::empty();
+
+        tmp.add_arg("con.test1.ip", "example.com");
+        tmp.add_arg("con.test1.ports.http", "http");
+
+        tmp
+    };
+
+    assert_eq!(
+        Result::<Options, _>::Err(ParseError::InvalidArgument {
+            name: "con.test1.ports.http".to_owned(),
+            error: Box::new(ParseError::InvalidValue),
+        }),
+        argser::parse_args_from_providers(&[&fixed_provider])
+    );
+}
+
+#[test]
+fn strict_vec_errors() {
+    #[argser]
+    #[derive(Debug, PartialEq)]
+    struct Options {
+        ports: Vec<u16>,
+    }
+
+    let fixed_provider = {
+        let mut tmp = argser::provider::Fixed::empty();
+
+        tmp.add_arg("ports", "80");
+        tmp.add_arg("ports", "http");
+
+        tmp
+    };
+
+    assert_eq!(
+        Result::<Options, _>::Err(ParseError::InvalidArgument {
+            name: "ports".to_owned(),
+            error: Box::new(ParseError::InvalidValue),
+        }),
+        argser::parse_args_from_providers(&[&fixed_provider])
+    );
+}
+
+#[test]
+fn lenient_with_warnings() {
+    #[argser]
+    #[derive(Debug, PartialEq)]
+    struct Options {
+        #[argser(map, lenient)]
+        con: u16,
+        #[argser(map(subcategory), lenient)]
+        backends: Backend,
+        #[argser(lenient)]
+        ports: Vec<u16>,
+    }
+
+    #[argser]
+    #[derive(Debug, PartialEq)]
+    struct Backend {
+        port: u16,
+    }
+
+    let fixed_provider = {
+        let mut tmp = argser::provider::Fixed::empty();
+
+        tmp.add_arg("con.port", "123");
+        tmp.add_arg("con.other", "abc");
+        tmp.add_arg("backends.first.port", "8080");
+        tmp.add_arg("backends.second.host", "example.com");
+        tmp.add_arg("ports", "80");
+        tmp.add_arg("ports", "http");
+
+        tmp
+    };
+
+    let expected = Options {
+        con: {
+            let mut tmp = HashMap::new();
+            tmp.insert("port".to_owned(), 123);
+            tmp
+        },
+        backends: {
+            let mut tmp = HashMap::new();
+            tmp.insert("first".to_owned(), Backend { port: 8080 });
+            tmp
+        },
+        ports: vec![80],
+    };
+
+    let mut ctx = ParseContext::new();
+    assert_eq!(
+        Ok(expected),
+        argser::parse_args_from_providers_with_context(&[&fixed_provider], &mut ctx)
+    );
+
+    let mut warnings = ctx.take_warnings();
+    warnings.sort_by(|a, b| a.name.cmp(&b.name));
+    assert_eq!(
+        vec![
+            Warning {
+                name: "backends.second".to_owned(),
+                error: ParseError::MissingParam {
+                    name: "port".to_owned(),
+                },
+            },
+            Warning {
+                name: "con.other".to_owned(),
+                error: ParseError::InvalidValue,
+            },
+            Warning {
+                name: "ports".to_owned(),
+                error: ParseError::InvalidValue,
+            },
+        ],
+        warnings
+    );
+}
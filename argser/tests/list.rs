@@ -0,0 +1,131 @@
+use argser::{argser, FromArgs};
+
+#[test]
+fn list_primitive() {
+    #[argser]
+    #[derive(Debug, PartialEq)]
+    struct Options {
+        #[argser(list)]
+        ports: u16,
+    }
+
+    let fixed_provider = {
+        let mut tmp = argser::provider::Fixed::empty();
+
+        tmp.add_arg("ports.1", "234");
+        tmp.add_arg("ports.0", "123");
+
+        tmp
+    };
+
+    let expected = Options {
+        ports: vec![123, 234],
+    };
+    assert_eq!(
+        Ok(expected),
+        argser::parse_args_from_providers(&[&fixed_provider])
+    );
+
+    let expected_args = vec![argser::ArgumentDetail::new("ports.{index}")];
+    assert_eq!(expected_args, Options::arguments());
+}
+
+#[test]
+fn list_subcategory() {
+    #[argser]
+    #[derive(Debug, PartialEq)]
+    struct Options {
+        name: String,
+        #[argser(list(subcategory))]
+        upstream: Upstream,
+    }
+
+    #[argser]
+    #[derive(Debug, PartialEq)]
+    struct Upstream {
+        host: String,
+        port: u16,
+    }
+
+    let fixed_provider = {
+        let mut tmp = argser::provider::Fixed::empty();
+
+        tmp.add_arg("name", "test-name");
+        tmp.add_arg("upstream.1.host", "second.example.com");
+        tmp.add_arg("upstream.1.port", "234");
+        tmp.add_arg("upstream.0.host", "first.example.com");
+        tmp.add_arg("upstream.0.port", "123");
+
+        tmp
+    };
+
+    let expected = Options {
+        name: "test-name".to_owned(),
+        upstream: vec![
+            Upstream {
+                host: "first.example.com".to_owned(),
+                port: 123,
+            },
+            Upstream {
+                host: "second.example.com".to_owned(),
+                port: 234,
+            },
+        ],
+    };
+    assert_eq!(
+        Ok(expected),
+        argser::parse_args_from_providers(&[&fixed_provider])
+    );
+
+    let expected_args = vec![
+        argser::ArgumentDetail::new("name").required(true),
+        argser::ArgumentDetail::new("upstream.{index}.host"),
+        argser::ArgumentDetail::new("upstream.{index}.port"),
+    ];
+    assert_eq!(expected_args, Options::arguments());
+}
+
+#[test]
+fn list_gaps_and_invalid_indices() {
+    #[argser]
+    #[derive(Debug, PartialEq)]
+    struct Options {
+        #[argser(list(subcategory))]
+        upstream: Upstream,
+    }
+
+    #[argser]
+    #[derive(Debug, PartialEq)]
+    struct Upstream {
+        host: String,
+    }
+
+    let gap_provider = {
+        let mut tmp = argser::provider::Fixed::empty();
+
+        tmp.add_arg("upstream.0.host", "first.example.com");
+        tmp.add_arg("upstream.2.host", "third.example.com");
+
+        tmp
+    };
+    assert_eq!(
+        Result::<Options, _>::Err(argser::ParseError::MissingIndex {
+            name: "upstream.1".to_owned()
+        }),
+        argser::parse_args_from_providers(&[&gap_provider])
+    );
+
+    let invalid_provider = {
+        let mut tmp = argser::provider::Fixed::empty();
+
+        tmp.add_arg("upstream.first.host", "first.example.com");
+
+        tmp
+    };
+    assert_eq!(
+        Result::<Options, _>::Err(argser::ParseError::InvalidIndex {
+            name: "upstream.first".to_owned()
+        }),
+        argser::parse_args_from_providers(&[&invalid_provider])
+    );
+}
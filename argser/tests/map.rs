@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 use argser::{argser, FromArgs};
 
@@ -37,16 +37,8 @@ fn map_primitive() {
     );
 
     let expected_args = vec![
-        argser::ArgumentDetail {
-            name: "name".to_owned(),
-            required: true,
-            description: "".to_owned(),
-        },
-        argser::ArgumentDetail {
-            name: "con.{name}".to_owned(),
-            required: false,
-            description: "".to_owned(),
-        },
+        argser::ArgumentDetail::new("name").required(true),
+        argser::ArgumentDetail::new("con.{name}"),
     ];
     assert_eq!(expected_args, Options::arguments());
 }
@@ -107,21 +99,155 @@ fn map_subcategory() {
     );
 
     let expected_args = vec![
-        argser::ArgumentDetail {
-            name: "name".to_owned(),
-            required: true,
-            description: "".to_owned(),
+        argser::ArgumentDetail::new("name").required(true),
+        argser::ArgumentDetail::new("con.{name}.ip"),
+        argser::ArgumentDetail::new("con.{name}.port"),
+    ];
+    assert_eq!(expected_args, Options::arguments());
+}
+
+#[test]
+fn map_typed_keys() {
+    #[argser]
+    #[derive(Debug, PartialEq)]
+    struct Options {
+        #[argser(map(key = u16, subcategory))]
+        backends: Backend,
+    }
+
+    #[argser]
+    #[derive(Debug, PartialEq)]
+    struct Backend {
+        host: String,
+    }
+
+    let fixed_provider = {
+        let mut tmp = argser::provider::Fixed::empty();
+
+        tmp.add_arg("backends.8080.host", "first.example.com");
+        tmp.add_arg("backends.9090.host", "second.example.com");
+
+        tmp
+    };
+
+    let expected = Options {
+        backends: {
+            let mut tmp = HashMap::new();
+            tmp.insert(
+                8080u16,
+                Backend {
+                    host: "first.example.com".to_owned(),
+                },
+            );
+            tmp.insert(
+                9090u16,
+                Backend {
+                    host: "second.example.com".to_owned(),
+                },
+            );
+            tmp
+        },
+    };
+    assert_eq!(
+        Ok(expected),
+        argser::parse_args_from_providers(&[&fixed_provider])
+    );
+}
+
+#[test]
+fn map_btree_of_vec() {
+    #[argser]
+    #[derive(Debug, PartialEq)]
+    struct Options {
+        #[argser(map(btree))]
+        ports: Vec<u16>,
+    }
+
+    let fixed_provider = {
+        let mut tmp = argser::provider::Fixed::empty();
+
+        tmp.add_arg("ports.web", "80");
+        tmp.add_arg("ports.web", "443");
+        tmp.add_arg("ports.admin", "8080");
+
+        tmp
+    };
+
+    let expected = Options {
+        ports: {
+            let mut tmp = BTreeMap::new();
+            tmp.insert("admin".to_owned(), vec![8080]);
+            tmp.insert("web".to_owned(), vec![80, 443]);
+            tmp
         },
-        argser::ArgumentDetail {
-            name: "con.{name}.ip".to_owned(),
-            required: false,
-            description: "".to_owned(),
+    };
+    assert_eq!(
+        Ok(expected),
+        argser::parse_args_from_providers(&[&fixed_provider])
+    );
+}
+
+#[test]
+fn map_nested() {
+    #[argser]
+    #[derive(Debug, PartialEq)]
+    struct Options {
+        #[argser(map(subcategory))]
+        region: Region,
+        #[argser(map(map))]
+        limits: u32,
+    }
+
+    #[argser]
+    #[derive(Debug, PartialEq)]
+    struct Region {
+        #[argser(map(btree, subcategory))]
+        zone: Zone,
+    }
+
+    #[argser]
+    #[derive(Debug, PartialEq)]
+    struct Zone {
+        capacity: u32,
+    }
+
+    let fixed_provider = {
+        let mut tmp = argser::provider::Fixed::empty();
+
+        tmp.add_arg("region.eu.zone.a.capacity", "10");
+        tmp.add_arg("region.eu.zone.b.capacity", "20");
+        tmp.add_arg("limits.eu.memory", "512");
+
+        tmp
+    };
+
+    let expected = Options {
+        region: {
+            let mut zones = BTreeMap::new();
+            zones.insert("a".to_owned(), Zone { capacity: 10 });
+            zones.insert("b".to_owned(), Zone { capacity: 20 });
+
+            let mut tmp = HashMap::new();
+            tmp.insert("eu".to_owned(), Region { zone: zones });
+            tmp
         },
-        argser::ArgumentDetail {
-            name: "con.{name}.port".to_owned(),
-            required: false,
-            description: "".to_owned(),
+        limits: {
+            let mut inner = HashMap::new();
+            inner.insert("memory".to_owned(), 512);
+
+            let mut tmp = HashMap::new();
+            tmp.insert("eu".to_owned(), inner);
+            tmp
         },
+    };
+    assert_eq!(
+        Ok(expected),
+        argser::parse_args_from_providers(&[&fixed_provider])
+    );
+
+    let expected_args = vec![
+        argser::ArgumentDetail::new("region.{name}.zone.{name}.capacity"),
+        argser::ArgumentDetail::new("limits.{name}.{name}"),
     ];
     assert_eq!(expected_args, Options::arguments());
 }
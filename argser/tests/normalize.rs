@@ -0,0 +1,172 @@
+use std::collections::HashMap;
+
+use argser::{argser, KeyFormat, ParseContext, ParseError};
+
+#[argser]
+#[derive(Debug, PartialEq)]
+struct Options {
+    max_conns: u16,
+    #[argser(subcategory)]
+    db: Database,
+}
+
+#[argser]
+#[derive(Debug, PartialEq)]
+struct Database {
+    port: u16,
+}
+
+fn context() -> ParseContext {
+    let mut format = KeyFormat::default();
+    format.fold_case(true);
+    format.unify_dashes(true);
+
+    let mut ctx = ParseContext::new();
+    ctx.set_key_format(format);
+    ctx
+}
+
+#[test]
+fn normalized_keys() {
+    let fixed_provider = {
+        let mut tmp = argser::provider::Fixed::empty();
+
+        tmp.add_arg("Max-Conns", "10");
+        tmp.add_arg("DB.PORT", "5432");
+
+        tmp
+    };
+
+    let expected = Options {
+        max_conns: 10,
+        db: Database { port: 5432 },
+    };
+    assert_eq!(
+        Ok(expected),
+        argser::parse_args_from_providers_with_context(&[&fixed_provider], &mut context())
+    );
+
+    assert_eq!(
+        Err(ParseError::MissingParam {
+            name: "max_conns".to_owned(),
+        }),
+        argser::parse_args_from_providers::<Options>(&[&fixed_provider])
+    );
+}
+
+#[test]
+fn override_across_providers() {
+    let cli_provider = {
+        let mut tmp = argser::provider::Fixed::empty();
+
+        tmp.add_arg("max-conns", "10");
+        tmp.add_arg("db.port", "5432");
+
+        tmp
+    };
+    let env_provider = {
+        let mut tmp = argser::provider::Fixed::empty();
+
+        tmp.add_arg("MAX_CONNS", "20");
+
+        tmp
+    };
+
+    let expected = Options {
+        max_conns: 10,
+        db: Database { port: 5432 },
+    };
+    assert_eq!(
+        Ok(expected),
+        argser::parse_args_from_providers_with_context(
+            &[&cli_provider, &env_provider],
+            &mut context()
+        )
+    );
+}
+
+#[test]
+fn collisions() {
+    let fixed_provider = {
+        let mut tmp = argser::provider::Fixed::empty();
+
+        tmp.add_arg("max_conns", "10");
+        tmp.add_arg("db.port", "5432");
+        tmp.add_arg("db.Port", "5433");
+
+        tmp
+    };
+
+    assert_eq!(
+        Err(ParseError::AmbiguousArgument {
+            name: "db.port".to_owned(),
+            first: "db.Port".to_owned(),
+            second: "db.port".to_owned(),
+        }),
+        argser::parse_args_from_providers_with_context::<Options>(
+            &[&fixed_provider],
+            &mut context()
+        )
+    );
+
+    // Collisions between Keys that are not used by any Field are ignored
+    let fixed_provider = {
+        let mut tmp = argser::provider::Fixed::empty();
+
+        tmp.add_arg("max_conns", "10");
+        tmp.add_arg("db.port", "5432");
+        tmp.add_arg("http_proxy", "localhost");
+        tmp.add_arg("HTTP_PROXY", "localhost");
+
+        tmp
+    };
+
+    let expected = Options {
+        max_conns: 10,
+        db: Database { port: 5432 },
+    };
+    assert_eq!(
+        Ok(expected),
+        argser::parse_args_from_providers_with_context(&[&fixed_provider], &mut context())
+    );
+}
+
+#[test]
+fn map_keys_are_kept() {
+    #[argser]
+    #[derive(Debug, PartialEq)]
+    struct MapOptions {
+        #[argser(map)]
+        labels: String,
+        #[argser(map(subcategory))]
+        backends: Database,
+    }
+
+    let fixed_provider = {
+        let mut tmp = argser::provider::Fixed::empty();
+
+        tmp.add_arg("labels.App", "web");
+        tmp.add_arg("labels.app", "api");
+        tmp.add_arg("BACKENDS.Primary.PORT", "5432");
+
+        tmp
+    };
+
+    let expected = MapOptions {
+        labels: {
+            let mut tmp = HashMap::new();
+            tmp.insert("App".to_owned(), "web".to_owned());
+            tmp.insert("app".to_owned(), "api".to_owned());
+            tmp
+        },
+        backends: {
+            let mut tmp = HashMap::new();
+            tmp.insert("Primary".to_owned(), Database { port: 5432 });
+            tmp
+        },
+    };
+    assert_eq!(
+        Ok(expected),
+        argser::parse_args_from_providers_with_context(&[&fixed_provider], &mut context())
+    );
+}
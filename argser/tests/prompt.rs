@@ -0,0 +1,153 @@
+use std::collections::HashMap;
+
+use argser::{argser, provider::Prompt, FromArgs, ParseContext};
+
+#[argser]
+#[derive(Debug, PartialEq)]
+struct Options {
+    name: String,
+    port: u16,
+    #[argser(secret)]
+    password: String,
+    #[argser(default = 10)]
+    max_conns: u16,
+    #[argser(subcategory)]
+    db: Database,
+}
+
+#[argser]
+#[derive(Debug, PartialEq)]
+struct Database {
+    port: u16,
+}
+
+#[test]
+fn prompt_missing() {
+    let fixed_provider = {
+        let mut tmp = argser::provider::Fixed::empty();
+
+        tmp.add_arg("name", "test-name");
+
+        tmp
+    };
+
+    let input = "invalid\n8080\nhunter2\n\n99999\n5432\n";
+    let mut output = Vec::new();
+    let mut prompt = Prompt::new(input.as_bytes(), &mut output);
+    prompt.ask_missing::<Options>(&[&fixed_provider]).unwrap();
+
+    let expected = Options {
+        name: "test-name".to_owned(),
+        port: 8080,
+        password: "hunter2".to_owned(),
+        max_conns: 10,
+        db: Database { port: 5432 },
+    };
+    assert_eq!(
+        Ok(expected),
+        argser::parse_args_from_providers(&[&fixed_provider, &prompt])
+    );
+
+    drop(prompt);
+    assert_eq!(
+        "port: Invalid Value: the Value could not be parsed\n\
+         port: \
+         password: \
+         db.port: A Value is required\n\
+         db.port: Invalid Value: the Value could not be parsed\n\
+         db.port: ",
+        String::from_utf8(output).unwrap()
+    );
+}
+
+#[test]
+fn secret_arguments() {
+    let secrets: Vec<_> = Options::arguments()
+        .into_iter()
+        .filter(|a| a.secret)
+        .map(|a| a.name)
+        .collect();
+    assert_eq!(vec!["password"], secrets);
+}
+
+#[test]
+fn prompt_with_context_env() {
+    #[argser]
+    #[derive(Debug, PartialEq)]
+    struct EnvOptions {
+        #[argser(env = "DATABASE_URL")]
+        database: String,
+        name: String,
+    }
+
+    let fixed_provider = argser::provider::Fixed::empty();
+
+    let mut ctx = ParseContext::new();
+    ctx.set_env({
+        let mut tmp = HashMap::new();
+        tmp.insert("DATABASE_URL".to_owned(), "postgres://localhost".to_owned());
+        tmp
+    });
+
+    let mut output = Vec::new();
+    let mut prompt = Prompt::new("test-name\n".as_bytes(), &mut output);
+    prompt
+        .ask_missing_with_context::<EnvOptions>(&[&fixed_provider], &ctx)
+        .unwrap();
+
+    let expected = EnvOptions {
+        database: "postgres://localhost".to_owned(),
+        name: "test-name".to_owned(),
+    };
+    assert_eq!(
+        Ok(expected),
+        argser::parse_args_from_providers_with_context(&[&fixed_provider, &prompt], &mut ctx)
+    );
+
+    drop(prompt);
+    assert_eq!("name: ", String::from_utf8(output).unwrap());
+}
+
+#[test]
+fn prompt_skips_file_indirection() {
+    #[argser]
+    #[derive(Debug, PartialEq)]
+    struct FileOptions {
+        #[argser(file_indirection, secret)]
+        password: String,
+        name: String,
+    }
+
+    let path = std::env::temp_dir().join(format!("argser-prompt-file-{}", std::process::id()));
+    std::fs::write(&path, "from-file\n").unwrap();
+
+    for file_key in ["password_FILE", "password.file"] {
+        let fixed_provider = {
+            let mut tmp = argser::provider::Fixed::empty();
+
+            tmp.add_arg(file_key, path.to_str().unwrap());
+
+            tmp
+        };
+
+        let mut output = Vec::new();
+        let mut prompt = Prompt::new("test-name\n".as_bytes(), &mut output);
+        prompt
+            .ask_missing::<FileOptions>(&[&fixed_provider])
+            .unwrap();
+
+        let expected = FileOptions {
+            password: "from-file".to_owned(),
+            name: "test-name".to_owned(),
+        };
+        assert_eq!(
+            Ok(expected),
+            argser::parse_args_from_providers(&[&fixed_provider, &prompt])
+        );
+
+        drop(prompt);
+        assert_eq!("name: ", String::from_utf8(output).unwrap());
+    }
+
+    std::fs::remove_file(&path).unwrap();
+}
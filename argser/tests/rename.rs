@@ -0,0 +1,235 @@
+use argser::{argser, FromArgs};
+
+#[test]
+fn rename_all_kebab_case() {
+    #[argser(rename_all = "kebab-case")]
+    #[derive(Debug, PartialEq)]
+    struct Options {
+        max_conns: u16,
+        #[argser(rename("LISTEN"))]
+        listen_addr: String,
+        #[argser(subcategory)]
+        tls_config: Tls,
+    }
+
+    #[argser]
+    #[derive(Debug, PartialEq)]
+    struct Tls {
+        cert_path: String,
+    }
+
+    let fixed_provider = {
+        let mut tmp = argser::provider::Fixed::empty();
+
+        tmp.add_arg("max-conns", "10");
+        tmp.add_arg("LISTEN", "0.0.0.0");
+        tmp.add_arg("tls-config.cert-path", "/etc/cert.pem");
+
+        tmp
+    };
+
+    let expected = Options {
+        max_conns: 10,
+        listen_addr: "0.0.0.0".to_owned(),
+        tls_config: Tls {
+            cert_path: "/etc/cert.pem".to_owned(),
+        },
+    };
+    assert_eq!(
+        Ok(expected),
+        argser::parse_args_from_providers(&[&fixed_provider])
+    );
+
+    let names: Vec<_> = Options::arguments().into_iter().map(|a| a.name).collect();
+    assert_eq!(vec!["max-conns", "LISTEN", "tls-config.cert-path"], names);
+
+    // Without a Parent, the SubCategory keeps the Names of its Fields
+    let names: Vec<_> = Tls::arguments().into_iter().map(|a| a.name).collect();
+    assert_eq!(vec!["cert_path"], names);
+    assert_eq!(
+        Some(Ok(())),
+        Options::validate_argument("tls-config.cert-path", &argser::Value::from("/tmp"))
+    );
+    assert_eq!(
+        None,
+        Options::validate_argument("tls-config.cert_path", &argser::Value::from("/tmp"))
+    );
+}
+
+#[test]
+fn rename_all_inherited() {
+    #[argser(rename_all = "SCREAMING_SNAKE_CASE")]
+    #[derive(Debug, PartialEq)]
+    struct Options {
+        #[argser(subcategory)]
+        db: Database,
+        #[argser(map(subcategory))]
+        backends: Backend,
+    }
+
+    #[argser(prefix = "conn")]
+    #[derive(Debug, PartialEq)]
+    struct Database {
+        max_conns: u16,
+        #[argser(rename("host_name"))]
+        host: String,
+        #[argser(subcategory)]
+        tls_config: Tls,
+    }
+
+    #[argser(rename_all = "kebab-case")]
+    #[derive(Debug, PartialEq)]
+    struct Tls {
+        cert_path: String,
+    }
+
+    #[argser]
+    #[derive(Debug, PartialEq)]
+    struct Backend {
+        read_timeout: u16,
+    }
+
+    let fixed_provider = {
+        let mut tmp = argser::provider::Fixed::empty();
+
+        tmp.add_arg("DB.conn.MAX_CONNS", "10");
+        tmp.add_arg("DB.conn.host_name", "localhost");
+        tmp.add_arg("DB.conn.TLS_CONFIG.cert-path", "/etc/cert.pem");
+        tmp.add_arg("BACKENDS.api.READ_TIMEOUT", "30");
+
+        tmp
+    };
+
+    let expected = Options {
+        db: Database {
+            max_conns: 10,
+            host: "localhost".to_owned(),
+            tls_config: Tls {
+                cert_path: "/etc/cert.pem".to_owned(),
+            },
+        },
+        backends: vec![("api".to_owned(), Backend { read_timeout: 30 })]
+            .into_iter()
+            .collect(),
+    };
+    assert_eq!(
+        Ok(expected),
+        argser::parse_args_from_providers(&[&fixed_provider])
+    );
+
+    let names: Vec<_> = Options::arguments().into_iter().map(|a| a.name).collect();
+    assert_eq!(
+        vec![
+            "DB.conn.MAX_CONNS",
+            "DB.conn.host_name",
+            "DB.conn.TLS_CONFIG.cert-path",
+            "BACKENDS.{name}.READ_TIMEOUT"
+        ],
+        names
+    );
+
+    let fixed_provider = {
+        let mut tmp = argser::provider::Fixed::empty();
+
+        tmp.add_arg("DB.conn.MAX_CONNS", "10");
+        tmp.add_arg("DB.conn.host_name", "localhost");
+
+        tmp
+    };
+    assert_eq!(
+        Result::<Options, _>::Err(argser::ParseError::MissingParam {
+            name: "DB.conn.TLS_CONFIG.cert-path".to_owned()
+        }),
+        argser::parse_args_from_providers(&[&fixed_provider])
+    );
+}
+
+#[test]
+fn rename_all_conventions() {
+    #[argser(rename_all = "camelCase")]
+    #[derive(Debug, PartialEq)]
+    struct Camel {
+        max_conns_total: u16,
+    }
+
+    #[argser(rename_all = "SCREAMING_SNAKE_CASE")]
+    #[derive(Debug, PartialEq)]
+    struct Screaming {
+        max_conns_total: u16,
+    }
+
+    #[argser(rename_all = "PascalCase")]
+    #[derive(Debug, PartialEq)]
+    struct Pascal {
+        max_conns_total: u16,
+    }
+
+    assert_eq!("maxConnsTotal", Camel::arguments()[0].name);
+    assert_eq!("MAX_CONNS_TOTAL", Screaming::arguments()[0].name);
+    assert_eq!("MaxConnsTotal", Pascal::arguments()[0].name);
+}
+
+#[test]
+fn prefix() {
+    #[argser(prefix = "app", rename_all = "kebab-case")]
+    #[derive(Debug, PartialEq)]
+    struct Options {
+        max_conns: u16,
+        #[argser(subcategory)]
+        db: Database,
+    }
+
+    #[argser(prefix = "conn")]
+    #[derive(Debug, PartialEq)]
+    struct Database {
+        host: String,
+    }
+
+    let fixed_provider = {
+        let mut tmp = argser::provider::Fixed::empty();
+
+        tmp.add_arg("app.max-conns", "10");
+        tmp.add_arg("app.db.conn.host", "localhost");
+
+        tmp
+    };
+
+    let expected = Options {
+        max_conns: 10,
+        db: Database {
+            host: "localhost".to_owned(),
+        },
+    };
+    assert_eq!(
+        Ok(expected),
+        argser::parse_args_from_providers(&[&fixed_provider])
+    );
+
+    let names: Vec<_> = Options::arguments().into_iter().map(|a| a.name).collect();
+    assert_eq!(vec!["app.max-conns", "app.db.conn.host"], names);
+}
+
+#[test]
+fn rename_all_uppercase_keeps_underscores() {
+    #[argser(rename_all = "UPPERCASE")]
+    #[derive(Debug, PartialEq)]
+    struct Options {
+        max_conns: u16,
+    }
+
+    let fixed_provider = {
+        let mut tmp = argser::provider::Fixed::empty();
+
+        tmp.add_arg("MAX_CONNS", "10");
+
+        tmp
+    };
+
+    assert_eq!(
+        Ok(Options { max_conns: 10 }),
+        argser::parse_args_from_providers(&[&fixed_provider])
+    );
+
+    let names: Vec<_> = Options::arguments().into_iter().map(|a| a.name).collect();
+    assert_eq!(vec!["MAX_CONNS"], names);
+}
@@ -0,0 +1,85 @@
+use argser::{Argument, Schema, Value, ValueType};
+
+#[test]
+fn schema_from_providers() {
+    let schema = Schema::new()
+        .argument(Argument::new("name").required(true).description("The Name"))
+        .argument(
+            Argument::new("port")
+                .value_type(ValueType::Integer)
+                .default("8080"),
+        )
+        .subcategory(
+            "db",
+            Schema::new().argument(Argument::new("host").required(true)),
+        );
+
+    let fixed_provider = {
+        let mut tmp = argser::provider::Fixed::empty();
+
+        tmp.add_arg("name", "test-name");
+        tmp.add_arg("db.host", "localhost");
+
+        tmp
+    };
+
+    let value = argser::parse_schema_from_providers(&schema, &[&fixed_provider]).unwrap();
+    assert_eq!(Some(&Value::from("test-name")), value.get("name"));
+    assert_eq!(Some(&Value::Integer(8080)), value.get("port"));
+    assert_eq!(Some(&Value::from("localhost")), value.pointer("db.host"));
+
+    let expected_args = vec![
+        argser::ArgumentDetail::new("name")
+            .required(true)
+            .description("The Name"),
+        argser::ArgumentDetail::new("port").default("8080"),
+        argser::ArgumentDetail::new("db.host").required(true),
+    ];
+    assert_eq!(expected_args, schema.arguments());
+    assert_eq!(
+        "-name    The Name [required]\n-port    [default: 8080]\n-db.host [required]\n",
+        schema.help()
+    );
+}
+
+#[test]
+fn schema_normalized_keys() {
+    let mut format = argser::KeyFormat::default();
+    format.fold_case(true);
+    format.unify_dashes(true);
+
+    let schema = Schema::new()
+        .key_format(format)
+        .argument(Argument::new("max_conns").value_type(ValueType::Integer))
+        .subcategory("db", Schema::new().argument(Argument::new("host")));
+
+    let fixed_provider = {
+        let mut tmp = argser::provider::Fixed::empty();
+
+        tmp.add_arg("Max-Conns", "10");
+        tmp.add_arg("DB.Host", "localhost");
+
+        tmp
+    };
+
+    let value = argser::parse_schema_from_providers(&schema, &[&fixed_provider]).unwrap();
+    assert_eq!(Some(&Value::Integer(10)), value.get("max_conns"));
+    assert_eq!(Some(&Value::from("localhost")), value.pointer("db.host"));
+
+    let fixed_provider = {
+        let mut tmp = argser::provider::Fixed::empty();
+
+        tmp.add_arg("max-conns", "10");
+        tmp.add_arg("MAX_CONNS", "20");
+
+        tmp
+    };
+    assert_eq!(
+        Err(argser::ParseError::AmbiguousArgument {
+            name: "max_conns".to_owned(),
+            first: "MAX_CONNS".to_owned(),
+            second: "max-conns".to_owned(),
+        }),
+        argser::parse_schema_from_providers(&schema, &[&fixed_provider])
+    );
+}
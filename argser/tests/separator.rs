@@ -0,0 +1,206 @@
+use std::collections::HashMap;
+
+use argser::{argser, FromArgs, KeyFormat, ParseContext};
+
+#[test]
+fn struct_separator() {
+    #[argser(separator = "/")]
+    #[derive(Debug, PartialEq)]
+    struct Options {
+        #[argser(subcategory)]
+        db: Database,
+        #[argser(map)]
+        backends: u16,
+    }
+
+    #[argser(separator = "/")]
+    #[derive(Debug, PartialEq)]
+    struct Database {
+        host: String,
+    }
+
+    let fixed_provider = {
+        let mut tmp = argser::provider::Fixed::empty();
+
+        tmp.add_arg("db/host", "localhost");
+        tmp.add_arg("backends/api.example.com", "80");
+
+        tmp
+    };
+
+    let expected = Options {
+        db: Database {
+            host: "localhost".to_owned(),
+        },
+        backends: {
+            let mut tmp = HashMap::new();
+            tmp.insert("api.example.com".to_owned(), 80);
+            tmp
+        },
+    };
+    assert_eq!(
+        Ok(expected),
+        argser::parse_args_from_providers(&[&fixed_provider])
+    );
+
+    let names: Vec<_> = Options::arguments().into_iter().map(|a| a.name).collect();
+    assert_eq!(vec!["db/host", "backends/{name}"], names);
+}
+
+#[test]
+fn context_separator() {
+    #[argser]
+    #[derive(Debug, PartialEq)]
+    struct Options {
+        #[argser(subcategory)]
+        db: Database,
+    }
+
+    #[argser]
+    #[derive(Debug, PartialEq)]
+    struct Database {
+        port: u16,
+    }
+
+    let fixed_provider = {
+        let mut tmp = argser::provider::Fixed::empty();
+
+        tmp.add_arg("db/port", "5432");
+
+        tmp
+    };
+
+    let mut ctx = ParseContext::new();
+    ctx.set_key_format(KeyFormat::new('/'));
+
+    let expected = Options {
+        db: Database { port: 5432 },
+    };
+    assert_eq!(
+        Ok(expected),
+        argser::parse_args_from_providers_with_context(&[&fixed_provider], &mut ctx)
+    );
+}
+
+#[test]
+fn quoted_segments() {
+    #[argser]
+    #[derive(Debug, PartialEq)]
+    struct Options {
+        #[argser(map(subcategory))]
+        backends: Backend,
+    }
+
+    #[argser]
+    #[derive(Debug, PartialEq)]
+    struct Backend {
+        port: u16,
+    }
+
+    let fixed_provider = {
+        let mut tmp = argser::provider::Fixed::empty();
+
+        tmp.add_arg("backends.\"api.example.com\".port", "443");
+        tmp.add_arg("backends.local.port", "8080");
+
+        tmp
+    };
+
+    let expected = Options {
+        backends: {
+            let mut tmp = HashMap::new();
+            tmp.insert("api.example.com".to_owned(), Backend { port: 443 });
+            tmp.insert("local".to_owned(), Backend { port: 8080 });
+            tmp
+        },
+    };
+    assert_eq!(
+        Ok(expected),
+        argser::parse_args_from_providers(&[&fixed_provider])
+    );
+
+    let fixed_provider = {
+        let mut tmp = argser::provider::Fixed::empty();
+
+        tmp.add_arg("backends.\"api.example.com\".port", "invalid");
+
+        tmp
+    };
+
+    assert_eq!(
+        Err(argser::ParseError::InvalidArgument {
+            name: "backends.\"api.example.com\".port".to_owned(),
+            error: Box::new(argser::ParseError::InvalidValue),
+        }),
+        argser::parse_args_from_providers::<Options>(&[&fixed_provider])
+    );
+}
+
+/// The Macro splits the Names into their Segments at compile-time, using its
+/// own Copy of the Rules of [`KeyFormat::split`], which is used for the Names
+/// supplied by the Providers. These are the Cases from the Tests of
+/// [`KeyFormat::split`], which are only found if both agree on the Segments
+#[test]
+fn compile_time_split_matches_key_format() {
+    #[argser]
+    #[derive(Debug, PartialEq)]
+    struct Options {
+        #[argser(rename("db.host"))]
+        host: String,
+        #[argser(rename("backends.\"api.example.com\".port"))]
+        port: u16,
+        #[argser(rename(r#"say."\"hi\"\\""#))]
+        greeting: String,
+        #[argser(rename("quote.in\"side"))]
+        quote: String,
+    }
+
+    #[argser(separator = "/")]
+    #[derive(Debug, PartialEq)]
+    struct SlashOptions {
+        #[argser(rename("backends/api.example.com/port"))]
+        port: u16,
+        #[argser(rename("\"a/b\"/c"))]
+        quoted: String,
+    }
+
+    let fixed_provider = {
+        let mut tmp = argser::provider::Fixed::empty();
+
+        tmp.add_arg("db.host", "localhost");
+        tmp.add_arg("backends.\"api.example.com\".port", "80");
+        tmp.add_arg(r#"say."\"hi\"\\""#, "hello");
+        tmp.add_arg("quote.in\"side", "inside");
+
+        tmp
+    };
+
+    let expected = Options {
+        host: "localhost".to_owned(),
+        port: 80,
+        greeting: "hello".to_owned(),
+        quote: "inside".to_owned(),
+    };
+    assert_eq!(
+        Ok(expected),
+        argser::parse_args_from_providers(&[&fixed_provider])
+    );
+
+    let fixed_provider = {
+        let mut tmp = argser::provider::Fixed::empty();
+
+        tmp.add_arg("backends/api.example.com/port", "80");
+        tmp.add_arg("\"a/b\"/c", "quoted");
+
+        tmp
+    };
+
+    let expected = SlashOptions {
+        port: 80,
+        quoted: "quoted".to_owned(),
+    };
+    assert_eq!(
+        Ok(expected),
+        argser::parse_args_from_providers(&[&fixed_provider])
+    );
+}
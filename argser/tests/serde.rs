@@ -0,0 +1,284 @@
+use std::collections::HashMap;
+
+use argser::{ArgProvider, KeyFormat, ParseError, Value};
+use serde::Deserialize;
+
+/// A Provider that supplies an already typed Tree of Arguments, like one
+/// for a structured Config-File would
+struct Tree(Value);
+
+impl ArgProvider for Tree {
+    fn get_args(&self) -> Vec<(String, String)> {
+        self.0.to_pairs()
+    }
+
+    fn try_get_value(&self, _: &KeyFormat) -> Result<Value, ParseError> {
+        Ok(self.0.clone())
+    }
+}
+
+/// Builds a Tree from the given Pairs of dotted Paths and Values
+fn tree(entries: Vec<(&str, Value)>) -> Tree {
+    let mut value = Value::table();
+    for (path, entry) in entries {
+        value.insert(path, entry);
+    }
+    Tree(value)
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct Database {
+    host: String,
+    port: u16,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct Options {
+    name: String,
+    debug: bool,
+    db: Database,
+    #[serde(default)]
+    tags: Vec<String>,
+    timeout: Option<u32>,
+}
+
+#[test]
+fn nested_structs() {
+    let fixed_provider = {
+        let mut tmp = argser::provider::Fixed::empty();
+
+        tmp.add_arg("name", "test");
+        tmp.add_arg("debug", "true");
+        tmp.add_arg("db.host", "localhost");
+        tmp.add_arg("db.port", "5432");
+        tmp.add_arg("tags", "first");
+        tmp.add_arg("tags", "second");
+
+        tmp
+    };
+
+    let expected = Options {
+        name: "test".to_owned(),
+        debug: true,
+        db: Database {
+            host: "localhost".to_owned(),
+            port: 5432,
+        },
+        tags: vec!["first".to_owned(), "second".to_owned()],
+        timeout: None,
+    };
+    assert_eq!(Ok(expected), argser::from_providers(&[&fixed_provider]));
+}
+
+#[test]
+fn typed_values() {
+    let fixed_provider = {
+        let mut tmp = argser::provider::Fixed::empty();
+
+        tmp.add_arg("name", "cli");
+
+        tmp
+    };
+    let tree = tree(vec![
+        ("name", Value::from("tree")),
+        ("debug", Value::Bool(false)),
+        ("db.host", Value::from("db")),
+        ("db.port", Value::Integer(5432)),
+        ("timeout", Value::Integer(30)),
+    ]);
+
+    let expected = Options {
+        name: "cli".to_owned(),
+        debug: false,
+        db: Database {
+            host: "db".to_owned(),
+            port: 5432,
+        },
+        tags: Vec::new(),
+        timeout: Some(30),
+    };
+    assert_eq!(
+        Ok(expected),
+        argser::from_providers(&[&fixed_provider, &tree])
+    );
+}
+
+#[test]
+fn collections() {
+    #[derive(Debug, PartialEq, Deserialize)]
+    enum Mode {
+        Fast,
+        Slow,
+    }
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Collections {
+        servers: Vec<Database>,
+        limits: HashMap<String, u8>,
+        mode: Mode,
+    }
+
+    let fixed_provider = {
+        let mut tmp = argser::provider::Fixed::empty();
+
+        tmp.add_arg("servers.0.host", "first");
+        tmp.add_arg("servers.0.port", "1");
+        tmp.add_arg("servers.1.host", "second");
+        tmp.add_arg("servers.1.port", "2");
+        tmp.add_arg("limits.cpu", "4");
+        tmp.add_arg("mode", "Slow");
+
+        tmp
+    };
+
+    let mut limits = HashMap::new();
+    limits.insert("cpu".to_owned(), 4);
+    let expected = Collections {
+        servers: vec![
+            Database {
+                host: "first".to_owned(),
+                port: 1,
+            },
+            Database {
+                host: "second".to_owned(),
+                port: 2,
+            },
+        ],
+        limits,
+        mode: Mode::Slow,
+    };
+    assert_eq!(Ok(expected), argser::from_providers(&[&fixed_provider]));
+}
+
+#[test]
+fn errors_contain_key_path() {
+    let fixed_provider = {
+        let mut tmp = argser::provider::Fixed::empty();
+
+        tmp.add_arg("name", "test");
+        tmp.add_arg("debug", "true");
+        tmp.add_arg("db.host", "localhost");
+        tmp.add_arg("db.port", "other");
+
+        tmp
+    };
+    assert_eq!(
+        Result::<Options, _>::Err(ParseError::InvalidArgument {
+            name: "db.port".to_owned(),
+            error: Box::new(ParseError::InvalidValue),
+        }),
+        argser::from_providers(&[&fixed_provider])
+    );
+
+    let fixed_provider = {
+        let mut tmp = argser::provider::Fixed::empty();
+
+        tmp.add_arg("name", "test");
+        tmp.add_arg("debug", "true");
+        tmp.add_arg("db.port", "80");
+
+        tmp
+    };
+    assert_eq!(
+        Result::<Options, _>::Err(ParseError::MissingParam {
+            name: "db.host".to_owned(),
+        }),
+        argser::from_providers(&[&fixed_provider])
+    );
+}
+
+#[test]
+fn custom_separator() {
+    let format = KeyFormat::new('/');
+    let fixed_provider = {
+        let mut tmp = argser::provider::Fixed::empty();
+
+        tmp.add_arg("name", "test");
+        tmp.add_arg("debug", "true");
+        tmp.add_arg("db/host", "localhost");
+        tmp.add_arg("db/port", "5432");
+
+        tmp
+    };
+
+    let expected = Options {
+        name: "test".to_owned(),
+        debug: true,
+        db: Database {
+            host: "localhost".to_owned(),
+            port: 5432,
+        },
+        tags: Vec::new(),
+        timeout: None,
+    };
+    assert_eq!(
+        Ok(expected),
+        argser::from_providers_with_format(&[&fixed_provider], &format)
+    );
+
+    let fixed_provider = {
+        let mut tmp = argser::provider::Fixed::empty();
+
+        tmp.add_arg("name", "test");
+        tmp.add_arg("debug", "true");
+        tmp.add_arg("db/port", "80");
+
+        tmp
+    };
+    assert_eq!(
+        Result::<Options, _>::Err(ParseError::MissingParam {
+            name: "db/host".to_owned(),
+        }),
+        argser::from_providers_with_format(&[&fixed_provider], &format)
+    );
+}
+
+#[test]
+fn normalized_keys() {
+    let mut format = KeyFormat::default();
+    format.fold_case(true);
+    format.unify_dashes(true);
+
+    let fixed_provider = {
+        let mut tmp = argser::provider::Fixed::empty();
+
+        tmp.add_arg("Name", "test");
+        tmp.add_arg("DEBUG", "true");
+        tmp.add_arg("DB.Host", "localhost");
+        tmp.add_arg("DB.PORT", "5432");
+
+        tmp
+    };
+
+    let expected = Options {
+        name: "test".to_owned(),
+        debug: true,
+        db: Database {
+            host: "localhost".to_owned(),
+            port: 5432,
+        },
+        tags: Vec::new(),
+        timeout: None,
+    };
+    assert_eq!(
+        Ok(expected),
+        argser::from_providers_with_format(&[&fixed_provider], &format)
+    );
+
+    let fixed_provider = {
+        let mut tmp = argser::provider::Fixed::empty();
+
+        tmp.add_arg("name", "test");
+        tmp.add_arg("Name", "other");
+
+        tmp
+    };
+    assert_eq!(
+        Result::<Options, _>::Err(ParseError::AmbiguousArgument {
+            name: "name".to_owned(),
+            first: "Name".to_owned(),
+            second: "name".to_owned(),
+        }),
+        argser::from_providers_with_format(&[&fixed_provider], &format)
+    );
+}
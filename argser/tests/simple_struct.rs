@@ -30,16 +30,8 @@ fn primitives() {
         argser::parse_args_from_providers(&[&fixed_provider])
     );
     let expected_args = vec![
-        argser::ArgumentDetail {
-            name: "name".to_owned(),
-            required: true,
-            description: "".to_owned(),
-        },
-        argser::ArgumentDetail {
-            name: "port".to_owned(),
-            required: true,
-            description: "".to_owned(),
-        },
+        argser::ArgumentDetail::new("name").required(true),
+        argser::ArgumentDetail::new("port").required(true),
     ];
     assert_eq!(expected_args, Options::arguments());
 }
@@ -90,16 +82,8 @@ fn primitives_with_default_func() {
     );
 
     let expected_args = vec![
-        argser::ArgumentDetail {
-            name: "name".to_owned(),
-            required: true,
-            description: "".to_owned(),
-        },
-        argser::ArgumentDetail {
-            name: "port".to_owned(),
-            required: false,
-            description: "".to_owned(),
-        },
+        argser::ArgumentDetail::new("name").required(true),
+        argser::ArgumentDetail::new("port"),
     ];
     assert_eq!(expected_args, Options::arguments());
 }
@@ -146,16 +130,8 @@ fn primitives_with_default() {
     );
 
     let expected_args = vec![
-        argser::ArgumentDetail {
-            name: "name".to_owned(),
-            required: true,
-            description: "".to_owned(),
-        },
-        argser::ArgumentDetail {
-            name: "port".to_owned(),
-            required: false,
-            description: "".to_owned(),
-        },
+        argser::ArgumentDetail::new("name").required(true),
+        argser::ArgumentDetail::new("port"),
     ];
     assert_eq!(expected_args, Options::arguments());
 }
@@ -0,0 +1,49 @@
+use argser::{argser, FromArgs};
+
+#[test]
+fn skip_and_compute() {
+    #[derive(Debug, PartialEq)]
+    struct Handle(u32);
+
+    fn address(host: &str, port: u16) -> String {
+        format!("{}:{}", host, port)
+    }
+
+    #[argser]
+    #[derive(Debug, PartialEq)]
+    struct Options {
+        #[argser(compute = address(&host, port))]
+        address: String,
+        host: String,
+        port: u16,
+        #[argser(skip)]
+        requests: Vec<String>,
+        #[argser(skip, default_expr = Handle(42))]
+        handle: Handle,
+    }
+
+    let fixed_provider = {
+        let mut tmp = argser::provider::Fixed::empty();
+
+        tmp.add_arg("host", "localhost");
+        tmp.add_arg("port", "8080");
+        tmp.add_arg("requests", "ignored");
+
+        tmp
+    };
+
+    let expected = Options {
+        address: "localhost:8080".to_owned(),
+        host: "localhost".to_owned(),
+        port: 8080,
+        requests: Vec::new(),
+        handle: Handle(42),
+    };
+    assert_eq!(
+        Ok(expected),
+        argser::parse_args_from_providers(&[&fixed_provider])
+    );
+
+    let names: Vec<_> = Options::arguments().into_iter().map(|a| a.name).collect();
+    assert_eq!(vec!["host", "port"], names);
+}
@@ -35,16 +35,8 @@ fn one_subcategory() {
     );
 
     let expected_args = vec![
-        argser::ArgumentDetail {
-            name: "name".to_owned(),
-            required: true,
-            description: "".to_owned(),
-        },
-        argser::ArgumentDetail {
-            name: "con.port".to_owned(),
-            required: true,
-            description: "".to_owned(),
-        },
+        argser::ArgumentDetail::new("name").required(true),
+        argser::ArgumentDetail::new("con.port").required(true),
     ];
     assert_eq!(expected_args, Options::arguments());
 }
@@ -0,0 +1,111 @@
+use argser::{argser, FromArgs, ParseError};
+
+#[argser]
+#[derive(Debug, PartialEq)]
+struct Port(u16);
+
+#[test]
+fn newtype() {
+    #[argser]
+    #[derive(Debug, PartialEq)]
+    struct Options {
+        port: Port,
+        #[argser(lenient)]
+        ports: Vec<Port>,
+    }
+
+    let fixed_provider = {
+        let mut tmp = argser::provider::Fixed::empty();
+
+        tmp.add_arg("port", "8080");
+        tmp.add_arg("ports", "80");
+        tmp.add_arg("ports", "http");
+
+        tmp
+    };
+
+    let expected = Options {
+        port: Port(8080),
+        ports: vec![Port(80)],
+    };
+    assert_eq!(
+        Ok(expected),
+        argser::parse_args_from_providers(&[&fixed_provider])
+    );
+}
+
+#[test]
+fn positional_bundle() {
+    #[argser]
+    #[derive(Debug, PartialEq)]
+    struct CopyArgs(String, String, #[argser(default)] bool);
+
+    let cli = {
+        let mut tmp: argser::provider::Cli = vec!["-verbose=1", "source.txt", "target.txt"]
+            .into_iter()
+            .collect();
+        tmp.collect_positional(true);
+        tmp
+    };
+
+    let expected = CopyArgs("source.txt".to_owned(), "target.txt".to_owned(), false);
+    assert_eq!(Ok(expected), argser::parse_args_from_providers(&[&cli]));
+
+    let names: Vec<_> = CopyArgs::arguments().into_iter().map(|a| a.name).collect();
+    assert_eq!(vec!["0", "1", "2"], names);
+    assert!(CopyArgs::positional());
+
+    let empty = argser::provider::Fixed::empty();
+    assert_eq!(
+        Result::<CopyArgs, _>::Err(ParseError::MissingParam {
+            name: "0".to_owned()
+        }),
+        argser::parse_args_from_providers(&[&empty])
+    );
+}
+
+#[test]
+fn unit_struct() {
+    #[argser]
+    #[derive(Debug, PartialEq)]
+    struct Empty;
+
+    let fixed_provider = argser::provider::Fixed::empty();
+
+    assert_eq!(
+        Ok(Empty),
+        argser::parse_args_from_providers(&[&fixed_provider])
+    );
+    assert!(Empty::arguments().is_empty());
+}
+
+#[test]
+fn named_struct_not_positional() {
+    #[argser]
+    #[derive(Debug, PartialEq)]
+    struct Options {
+        source: String,
+    }
+
+    assert!(!Options::positional());
+
+    // The same Arguments `parse_cli` would collect for this Struct
+    let cli = {
+        let mut tmp: argser::provider::Cli = vec!["-source", "source.txt", "target.txt"]
+            .into_iter()
+            .collect();
+        tmp.collect_positional(Options::positional());
+        tmp
+    };
+
+    assert_eq!(
+        vec![("source".to_owned(), "source.txt".to_owned())],
+        argser::ArgProvider::get_args(&cli)
+    );
+    assert_eq!(
+        Ok(Options {
+            source: "source.txt".to_owned()
+        }),
+        argser::parse_args_from_providers(&[&cli])
+    );
+}
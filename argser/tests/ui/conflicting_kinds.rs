@@ -0,0 +1,9 @@
+use argser::argser;
+
+#[argser]
+struct Options {
+    #[argser(map, list)]
+    ports: u16,
+}
+
+fn main() {}
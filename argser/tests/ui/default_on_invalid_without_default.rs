@@ -0,0 +1,9 @@
+use argser::argser;
+
+#[argser]
+struct Options {
+    #[argser(default_on_invalid)]
+    port: u16,
+}
+
+fn main() {}
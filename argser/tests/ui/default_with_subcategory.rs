@@ -0,0 +1,15 @@
+use argser::argser;
+
+#[argser]
+struct Options {
+    #[argser(subcategory, default)]
+    con: Connection,
+}
+
+#[argser]
+#[derive(Default)]
+struct Connection {
+    port: u16,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use argser::FromArgs;
+
+#[derive(FromArgs)]
+struct Options {
+    #[argser(map)]
+    ports: u16,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use argser::argser;
+
+#[argser]
+struct Options {
+    #[argser(map, env = "PORTS")]
+    ports: u16,
+}
+
+fn main() {}
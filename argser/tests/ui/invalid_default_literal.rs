@@ -0,0 +1,9 @@
+use argser::argser;
+
+#[argser]
+struct Options {
+    #[argser(default = b"8080")]
+    port: u16,
+}
+
+fn main() {}
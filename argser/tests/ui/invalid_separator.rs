@@ -0,0 +1,8 @@
+use argser::argser;
+
+#[argser(separator = "::")]
+struct Options {
+    max_conns: u16,
+}
+
+fn main() {}
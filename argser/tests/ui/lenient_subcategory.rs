@@ -0,0 +1,14 @@
+use argser::argser;
+
+#[argser]
+struct Options {
+    #[argser(subcategory, lenient)]
+    con: Connection,
+}
+
+#[argser]
+struct Connection {
+    port: u16,
+}
+
+fn main() {}
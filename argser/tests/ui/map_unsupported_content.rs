@@ -0,0 +1,9 @@
+use argser::argser;
+
+#[argser]
+struct Options {
+    #[argser(map(lenient))]
+    ports: u16,
+}
+
+fn main() {}
@@ -0,0 +1,6 @@
+use argser::argser;
+
+#[argser]
+struct Port(#[argser(default)] u16);
+
+fn main() {}
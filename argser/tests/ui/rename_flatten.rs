@@ -0,0 +1,14 @@
+use argser::argser;
+
+#[argser]
+struct Options {
+    #[argser(flatten, rename("log"))]
+    logging: Logging,
+}
+
+#[argser]
+struct Logging {
+    level: String,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use argser::argser;
+
+#[argser]
+struct Options {
+    #[argser(defualt)]
+    port: u16,
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use argser::argser;
+
+#[argser(rename_all = "kebab")]
+struct Options {
+    max_conns: u16,
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use argser::argser;
+
+#[argser(prefx = "app")]
+struct Options {
+    port: u16,
+}
+
+fn main() {}
@@ -0,0 +1,200 @@
+use std::collections::HashMap;
+
+use argser::{argser, ArgProvider, Argument, KeyFormat, ParseError, Schema, Value, ValueType};
+
+/// A Provider that supplies an already typed Tree of Arguments, like one
+/// for a structured Config-File would
+struct Tree(Value);
+
+impl ArgProvider for Tree {
+    fn get_args(&self) -> Vec<(String, String)> {
+        self.0.to_pairs()
+    }
+
+    fn try_get_value(&self, _: &KeyFormat) -> Result<Value, ParseError> {
+        Ok(self.0.clone())
+    }
+}
+
+/// Builds a Tree from the given Pairs of dotted Paths and Values
+fn tree(entries: Vec<(&str, Value)>) -> Tree {
+    let mut value = Value::table();
+    for (path, entry) in entries {
+        value.insert(path, entry);
+    }
+    Tree(value)
+}
+
+#[test]
+fn typed_scalars() {
+    #[argser]
+    #[derive(Debug, PartialEq)]
+    struct Options {
+        port: u16,
+        debug: bool,
+        name: String,
+    }
+
+    let tree = tree(vec![
+        ("port", Value::Integer(8080)),
+        ("debug", Value::Bool(true)),
+        ("name", Value::Integer(5)),
+    ]);
+
+    let expected = Options {
+        port: 8080,
+        debug: true,
+        name: "5".to_owned(),
+    };
+    assert_eq!(Ok(expected), argser::parse_args_from_providers(&[&tree]));
+}
+
+#[test]
+fn out_of_range() {
+    #[argser]
+    #[derive(Debug, PartialEq)]
+    struct Options {
+        port: u16,
+    }
+
+    let tree = tree(vec![("port", Value::Integer(70000))]);
+
+    assert_eq!(
+        Result::<Options, _>::Err(ParseError::InvalidArgument {
+            name: "port".to_owned(),
+            error: Box::new(ParseError::InvalidValue),
+        }),
+        argser::parse_args_from_providers(&[&tree])
+    );
+}
+
+#[test]
+fn nested_arrays() {
+    #[argser]
+    #[derive(Debug, PartialEq)]
+    struct Options {
+        matrix: Vec<Vec<u8>>,
+        #[argser(list)]
+        names: String,
+    }
+
+    let tree = tree(vec![
+        (
+            "matrix",
+            Value::from(vec![Value::from(vec![1i64, 2]), Value::from(vec![3i64])]),
+        ),
+        ("names", Value::from(vec!["a", "b"])),
+    ]);
+
+    let expected = Options {
+        matrix: vec![vec![1, 2], vec![3]],
+        names: vec!["a".to_owned(), "b".to_owned()],
+    };
+    assert_eq!(Ok(expected), argser::parse_args_from_providers(&[&tree]));
+}
+
+#[test]
+fn keys_with_dots() {
+    #[argser]
+    #[derive(Debug, PartialEq)]
+    struct Backend {
+        port: u16,
+    }
+
+    #[argser]
+    #[derive(Debug, PartialEq)]
+    struct Options {
+        #[argser(map(subcategory))]
+        backends: Backend,
+    }
+
+    let tree = tree(vec![(
+        "backends.\"api.example.com\".port",
+        Value::Integer(443),
+    )]);
+
+    let mut backends = HashMap::new();
+    backends.insert("api.example.com".to_owned(), Backend { port: 443 });
+    assert_eq!(
+        Ok(Options { backends }),
+        argser::parse_args_from_providers(&[&tree])
+    );
+}
+
+#[test]
+fn merged_with_other_providers() {
+    #[argser]
+    #[derive(Debug, PartialEq)]
+    struct Database {
+        host: String,
+        port: u16,
+    }
+
+    #[argser]
+    #[derive(Debug, PartialEq)]
+    struct Options {
+        #[argser(subcategory)]
+        db: Database,
+    }
+
+    let fixed_provider = {
+        let mut tmp = argser::provider::Fixed::empty();
+
+        tmp.add_arg("db.host", "override");
+
+        tmp
+    };
+    let tree = tree(vec![
+        ("db.host", Value::from("localhost")),
+        ("db.port", Value::Integer(5432)),
+    ]);
+
+    let expected = Options {
+        db: Database {
+            host: "override".to_owned(),
+            port: 5432,
+        },
+    };
+    assert_eq!(
+        Ok(expected),
+        argser::parse_args_from_providers(&[&fixed_provider, &tree])
+    );
+}
+
+#[test]
+fn schema_typed_values() {
+    let schema = Schema::new()
+        .argument(Argument::new("port").value_type(ValueType::Integer))
+        .argument(Argument::new("ratio").value_type(ValueType::Float))
+        .argument(Argument::new("debug").value_type(ValueType::Bool))
+        .argument(Argument::new("name"))
+        .argument(
+            Argument::new("ports")
+                .value_type(ValueType::Integer)
+                .multiple(true),
+        );
+
+    let typed = tree(vec![
+        ("port", Value::Integer(8080)),
+        ("ratio", Value::Integer(2)),
+        ("debug", Value::Bool(true)),
+        ("name", Value::Integer(5)),
+        ("ports", Value::from(vec![80i64, 443])),
+    ]);
+
+    let value = argser::parse_schema_from_providers(&schema, &[&typed]).unwrap();
+    assert_eq!(Some(&Value::Integer(8080)), value.get("port"));
+    assert_eq!(Some(&Value::Float(2.0)), value.get("ratio"));
+    assert_eq!(Some(&Value::Bool(true)), value.get("debug"));
+    assert_eq!(Some(&Value::from("5")), value.get("name"));
+    assert_eq!(Some(&Value::from(vec![80i64, 443])), value.get("ports"));
+
+    let invalid = tree(vec![("debug", Value::Integer(1))]);
+    assert_eq!(
+        Err(ParseError::InvalidArgument {
+            name: "debug".to_owned(),
+            error: Box::new(ParseError::InvalidValue),
+        }),
+        argser::parse_schema_from_providers(&schema, &[&invalid])
+    );
+}